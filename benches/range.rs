@@ -0,0 +1,32 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use buckets::bucketize::BucketizeSingle;
+use buckets::bucketizers::range::RangeBucketizer;
+
+const NUM_RANGES: i64 = 5_000;
+const NUM_VALUES: i64 = 200_000;
+
+fn bench<B: BucketizeSingle<i64>>(name: &str, bucketizer: &B, values: &[i64]) {
+    let start = Instant::now();
+    let total: usize = values
+        .iter()
+        .map(|value| bucketizer.bucketize(black_box(value)))
+        .sum();
+    println!("{name:>10}: {:?} (checksum {total})", start.elapsed());
+}
+
+fn main() {
+    let ranges: Vec<(i64, i64)> = (0..NUM_RANGES)
+        .map(|i| (i * 10, (i + 1) * 10))
+        .collect();
+    let values: Vec<i64> = (0..NUM_VALUES)
+        .map(|i| (i * 7919) % (NUM_RANGES * 10))
+        .collect();
+
+    let linear = RangeBucketizer::new(ranges.clone());
+    let sorted = RangeBucketizer::new_sorted(ranges);
+
+    bench("new", &linear, &values);
+    bench("new_sorted", &sorted, &values);
+}
@@ -0,0 +1,34 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use buckets::bucketize::BucketizeSingle;
+use buckets::bucketizers::quantile::QuantileBucketizer;
+use buckets::bucketizers::range::RangeBucketizer;
+
+const NUM_BUCKETS: i64 = 5_000;
+const NUM_VALUES: i64 = 200_000;
+
+fn bench(name: &str, bucketize: impl Fn(&[i64]) -> Vec<usize>, values: &[i64]) {
+    let start = Instant::now();
+    let total: usize = bucketize(black_box(values)).iter().sum();
+    println!("{name:>16}: {:?} (checksum {total})", start.elapsed());
+}
+
+fn main() {
+    let ranges: Vec<(i64, i64)> = (0..NUM_BUCKETS)
+        .map(|i| (i * 10, (i + 1) * 10))
+        .collect();
+    let mut values: Vec<i64> = (0..NUM_VALUES)
+        .map(|i| (i * 7919) % (NUM_BUCKETS * 10))
+        .collect();
+    values.sort();
+
+    let range = RangeBucketizer::new(ranges);
+    bench("range slice", |data| range.bucketize_slice(data), &values);
+    bench("range sorted", |data| range.bucketize_sorted(data), &values);
+
+    let quantiles: Vec<i64> = (1..NUM_BUCKETS).map(|i| i * 10).collect();
+    let quantile = QuantileBucketizer::new(quantiles, NUM_BUCKETS as usize);
+    bench("quantile slice", |data| quantile.bucketize_slice(data), &values);
+    bench("quantile sorted", |data| quantile.bucketize_sorted(data), &values);
+}
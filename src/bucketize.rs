@@ -1,5 +1,423 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::{BTreeMap, BTreeSet}, vec, vec::Vec};
+use core::error::Error;
+use core::fmt;
+use core::ops::{Add, Div, Sub};
+use core::str::FromStr;
+use num_traits::FromPrimitive;
+#[cfg(feature = "alloc")]
+use crate::histogram::cumulative;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The core bucketization trait, mapping a single value to a bucket index.
+///
+/// `BucketizeSingle` is object safe, so bucketizers chosen at runtime can 
+/// be stored behind a `Box<dyn BucketizeSingle<T>>`. The iterator-based 
+/// methods live on `Bucketize`, which is generic over the iterator type 
+/// and therefore cannot be used as a trait object.
+///
+/// # Example
+///
+/// ```
+/// use buckets::bucketize::BucketizeSingle;
+/// use buckets::bucketizers::linear::LinearBucketizer;
+/// use buckets::bucketizers::range::RangeBucketizer;
+///
+/// let bucketizers: Vec<Box<dyn BucketizeSingle<f64>>> = vec![
+///     LinearBucketizer::new(0.0, 20.0, 4.0).boxed(),
+///     Box::new(RangeBucketizer::new(vec![(0.0, 10.0), (10.0, 100.0)])),
+/// ];
+///
+/// let binned: Vec<usize> = bucketizers.iter().map(|b| b.bucketize(&12.0)).collect();
+///
+/// assert_eq!(binned, vec![2, 1]);
+/// ```
 pub trait BucketizeSingle<T: PartialOrd + Copy> {
     fn bucketize(&self, item: &T) -> usize;
+
+    /// Boxes the bucketizer as a `dyn BucketizeSingle<T>` trait object.
+    #[cfg(feature = "alloc")]
+    fn boxed<'a>(self) -> Box<dyn BucketizeSingle<T> + 'a>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Returns references to the values in `data` that are assigned to 
+    /// `bucket`, preserving their order.
+    ///
+    /// Slice-based helpers live on `BucketizeSingle` rather than 
+    /// `Bucketize` because they do not depend on an iterator type, and 
+    /// are therefore available to every `Bucketize` implementor as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let data = vec![1, 6, 7, 12, 9];
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20)]);
+    ///
+    /// assert_eq!(bucketizer.filter_bucket(&data, 1), vec![&6, &7, &9]);
+    /// assert!(bucketizer.filter_bucket(&data, 7).is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn filter_bucket<'a>(&self, data: &'a [T], bucket: usize) -> Vec<&'a T> {
+        data.iter()
+            .filter(|value| self.bucketize(value) == bucket)
+            .collect()
+    }
+
+    /// Bucketizes every element of a borrowed slice, leaving the 
+    /// original data untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![1.0, 6.0, 11.0, 25.0];
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// assert_eq!(bucketizer.bucketize_slice(&data), vec![0, 1, 2, 5]);
+    ///
+    /// // `data` was only borrowed and can still be used
+    /// assert_eq!(data.len(), 4);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn bucketize_slice(&self, data: &[T]) -> Vec<usize> {
+        data.iter()
+            .map(|value| self.bucketize(value))
+            .collect()
+    }
+
+    /// Returns the number of distinct buckets that the values in `data` 
+    /// are assigned to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let data = vec![1.0, 2.0, 3.0, 16.0, 19.0];
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// assert_eq!(bucketizer.occupied_buckets(&data), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn occupied_buckets(&self, data: &[T]) -> usize {
+        data.iter()
+            .map(|value| self.bucketize(value))
+            .collect::<BTreeSet<usize>>()
+            .len()
+    }
+
+    /// Bucketizes every element of `data` and tallies the per-bucket 
+    /// counts in the same pass, returning `(indices, counts)`.
+    ///
+    /// The counts vector is sized from `BucketCount::num_buckets` when 
+    /// the bucket count is known, so empty trailing buckets are included, 
+    /// and is otherwise grown to one more than the highest index observed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let data = vec![1.0, 2.0, 6.0, 11.0];
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    /// let (indices, counts) = bucketizer.bucketize_with_counts(&data);
+    /// assert_eq!(indices, vec![0, 0, 1, 2]);
+    /// assert_eq!(counts, vec![2, 1, 1, 0]);
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    /// let (indices, counts) = bucketizer.bucketize_with_counts(&data);
+    /// assert_eq!(indices, vec![0, 0, 1, 2]);
+    /// assert_eq!(counts, vec![2, 1, 1]);
+    ///
+    /// // The counts always match a manual tally of the indices
+    /// for (bucket, count) in counts.iter().enumerate() {
+    ///     assert_eq!(indices.iter().filter(|&&index| index == bucket).count(), *count);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn bucketize_with_counts(&self, data: &[T]) -> (Vec<usize>, Vec<usize>)
+    where
+        Self: BucketCount + Sized,
+    {
+        let mut indices = Vec::with_capacity(data.len());
+        let mut counts = vec![0; self.num_buckets().unwrap_or(0)];
+        for value in data {
+            let bucket = self.bucketize(value);
+            if bucket >= counts.len() {
+                counts.resize(bucket + 1, 0);
+            }
+            counts[bucket] += 1;
+            indices.push(bucket);
+        }
+        (indices, counts)
+    }
+
+    /// Bucketizes the `Ok` values of `iter`, stopping at and returning the 
+    /// first `Err`, e.g. when bucketizing values as they are parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// let parsed = ["1.0", "6.0", "11.0"].iter().map(|s| s.parse::<f64>());
+    /// assert_eq!(bucketizer.try_bucketize_results(parsed), Ok(vec![0, 1, 2]));
+    ///
+    /// let parsed = ["1.0", "6.0", "eleven", "x"].iter().map(|s| s.parse::<f64>());
+    /// let error = bucketizer.try_bucketize_results(parsed).unwrap_err();
+    /// assert_eq!(error, "eleven".parse::<f64>().unwrap_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_bucketize_results<I, E>(&self, iter: I) -> Result<Vec<usize>, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+        Self: Sized,
+    {
+        iter.map(|value| value.map(|value| self.bucketize(&value))).collect()
+    }
+
+    /// Parses `s` as a `T` and bucketizes it, e.g. for numbers read from 
+    /// logs. Surrounding whitespace is ignored, and a string that does not 
+    /// parse returns `T`'s parse error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// assert_eq!(bucketizer.bucketize_str("12.5"), Ok(2));
+    /// assert_eq!(bucketizer.bucketize_str(" 3\n"), Ok(0));
+    /// assert_eq!(bucketizer.bucketize_str("twelve"), Err("twelve".parse::<f64>().unwrap_err()));
+    /// ```
+    fn bucketize_str(&self, s: &str) -> Result<usize, T::Err>
+    where
+        T: FromStr,
+    {
+        s.trim().parse().map(|value| self.bucketize(&value))
+    }
+
+    /// Bucketizes every element of `data` and returns the count of each 
+    /// occupied bucket, ordered by bucket index. Unlike a dense histogram, 
+    /// empty buckets take no space, which suits sparse, high indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![1, 3, 5_002, 4, 5_000];
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// let counts = bucketizer.sparse_histogram(&data);
+    ///
+    /// assert_eq!(counts, BTreeMap::from([(0, 3), (1000, 2)]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sparse_histogram(&self, data: &[T]) -> BTreeMap<usize, usize> {
+        let mut counts = BTreeMap::new();
+        for value in data {
+            *counts.entry(self.bucketize(value)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Tallies the buckets of `data` into a fixed-size array, without 
+    /// allocating. Indices of `N` or more are clamped into the last slot, 
+    /// and an empty array is returned when `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![1.0, 2.0, 6.0, 11.0, 16.0, 250.0];
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// let counts: [usize; 4] = bucketizer.histogram_array(&data);
+    ///
+    /// // 250.0 belongs in bucket 50, which is clamped into slot 3
+    /// assert_eq!(counts, [2, 1, 1, 2]);
+    /// ```
+    fn histogram_array<const N: usize>(&self, data: &[T]) -> [usize; N]
+    where
+        Self: Sized,
+    {
+        let mut counts = [0; N];
+        if N == 0 {
+            return counts;
+        }
+        for value in data {
+            counts[self.bucketize(value).min(N - 1)] += 1;
+        }
+        counts
+    }
+
+    /// Bucketizes every element of `data` and reports how many values 
+    /// fell outside the bucketizer's domain, and were therefore clamped 
+    /// into a boundary bucket.
+    ///
+    /// Out-of-domain values below the lower edge of bucket 0 are counted 
+    /// as `below`, and all other out-of-domain values as `above`. Many 
+    /// clamped values usually mean the configured range does not fit the 
+    /// data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, ClampReport};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let data = vec![-5.0, -1.0, 2.0, 7.0, 12.0, 25.0, 30.0, 100.0];
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// let (indices, report) = bucketizer.bucketize_with_clamp_report(&data);
+    ///
+    /// assert_eq!(indices, vec![0, 0, 0, 1, 2, 3, 3, 3]);
+    /// assert_eq!(report, ClampReport { below: 2, above: 3 });
+    /// assert_eq!(report.total(), 5);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn bucketize_with_clamp_report(&self, data: &[T]) -> (Vec<usize>, ClampReport)
+    where
+        Self: BucketDomain<T> + BucketBounds<T> + Sized,
+    {
+        let mut report = ClampReport::default();
+        let indices = data
+            .iter()
+            .map(|value| {
+                let result = self.bucketize_detailed(value);
+                match result.clamped {
+                    Clamp::Below => report.below += 1,
+                    Clamp::Above => report.above += 1,
+                    Clamp::InRange => {}
+                }
+                result.index
+            })
+            .collect();
+        (indices, report)
+    }
+
+    /// Bucketizes a single value and reports whether it was clamped into 
+    /// a boundary bucket because it fell below or above the bucketizer's 
+    /// domain.
+    ///
+    /// Out-of-domain values below the lower edge of bucket 0 are reported 
+    /// as `Clamp::Below`, and all other out-of-domain values as 
+    /// `Clamp::Above`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketResult, BucketizeSingle, Clamp};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// assert_eq!(
+    ///     bucketizer.bucketize_detailed(&-3.0),
+    ///     BucketResult { index: 0, clamped: Clamp::Below },
+    /// );
+    /// assert_eq!(
+    ///     bucketizer.bucketize_detailed(&7.0),
+    ///     BucketResult { index: 1, clamped: Clamp::InRange },
+    /// );
+    /// assert_eq!(
+    ///     bucketizer.bucketize_detailed(&25.0),
+    ///     BucketResult { index: 3, clamped: Clamp::Above },
+    /// );
+    /// assert!(!bucketizer.bucketize_detailed(&19.0).is_clamped());
+    /// ```
+    fn bucketize_detailed(&self, value: &T) -> BucketResult
+    where
+        Self: BucketDomain<T> + BucketBounds<T> + Sized,
+    {
+        BucketResult { index: self.bucketize(value), clamped: clamp_of(self, value) }
+    }
+
+    /// Wraps the bucketizer so that values below or above its domain are 
+    /// assigned to the sentinel buckets `UNDERFLOW_BUCKET` and 
+    /// `OVERFLOW_BUCKET` instead of being clamped into the first or last 
+    /// bucket.
+    ///
+    /// Which side a value is on is decided as for `bucketize_detailed`. 
+    /// The sentinels are the largest possible indices, so keep them away 
+    /// from dense per-bucket storage such as `histogram`, and prefer 
+    /// `sparse_histogram`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, OVERFLOW_BUCKET, UNDERFLOW_BUCKET};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0).with_outlier_buckets();
+    ///
+    /// assert_eq!(bucketizer.bucketize(&-3.0), UNDERFLOW_BUCKET);
+    /// assert_eq!(bucketizer.bucketize(&25.0), OVERFLOW_BUCKET);
+    /// assert_eq!(bucketizer.bucketize_slice(&[0.0, 7.0, 19.0]), vec![0, 1, 3]);
+    /// ```
+    fn with_outlier_buckets(self) -> OutlierBuckets<Self>
+    where
+        Self: BucketDomain<T> + BucketBounds<T> + Sized,
+    {
+        OutlierBuckets { bucketizer: self }
+    }
+}
+
+/// Returns on which side of `bucketizer`'s domain `value` lies.
+fn clamp_of<T, B>(bucketizer: &B, value: &T) -> Clamp
+where
+    T: PartialOrd + Copy,
+    B: BucketDomain<T> + BucketBounds<T>,
+{
+    if bucketizer.in_domain(value) {
+        return Clamp::InRange;
+    }
+    match bucketizer.bucket_bounds(0) {
+        Some((low, _)) if *value < low => Clamp::Below,
+        _ => Clamp::Above,
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B> BucketizeSingle<T> for Box<B>
+where
+    T: PartialOrd + Copy,
+    B: BucketizeSingle<T> + ?Sized,
+{
+    fn bucketize(&self, item: &T) -> usize {
+        (**self).bucketize(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: BucketCount + ?Sized> BucketCount for Box<B> {
+    fn num_buckets(&self) -> Option<usize> {
+        (**self).num_buckets()
+    }
 }
 
 pub trait Bucketize<T, I>: BucketizeSingle<T> 
@@ -7,6 +425,7 @@ where
     T: PartialOrd + Copy,
     I: Iterator<Item = T>,
 {
+    #[cfg(feature = "alloc")]
     fn bucketize_iter(
         &self, 
         iter: I, 
@@ -17,4 +436,600 @@ where
         }).collect::<Vec<usize>>()
         
     }
+
+    /// Lazily bucketizes every item of `iter`, returning an iterator of 
+    /// bucket indices instead of collecting them into a `Vec` like 
+    /// `bucketize_iter`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::Bucketize;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// let in_second_bucket = bucketizer
+    ///     .bucketize_lazy(0..100)
+    ///     .filter(|&bucket| bucket == 1)
+    ///     .count();
+    ///
+    /// assert_eq!(in_second_bucket, 5);
+    /// ```
+    fn bucketize_lazy<'a>(&'a self, iter: I) -> impl Iterator<Item = usize> + 'a
+    where
+        I: 'a,
+    {
+        iter.map(move |value| self.bucketize(&value))
+    }
+
+    /// Bucketizes every item of `iter` and returns the number of items 
+    /// that landed in each bucket, where element `i` is the count for 
+    /// bucket `i`.
+    ///
+    /// If the bucketizer reports a bucket count, the returned vector has 
+    /// one element per bucket, including trailing buckets that received 
+    /// no items. Unbounded bucketizers grow the vector as needed, so its 
+    /// length is one more than the highest bucket index observed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::Bucketize;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let data = vec![1, 1, 6, 11];
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// let counts = bucketizer.histogram(data.clone().into_iter());
+    /// assert_eq!(counts, vec![2, 1, 1]);
+    ///
+    /// // The last of the four linear buckets is empty, but still counted
+    /// let bucketizer = LinearBucketizer::new(0, 20, 4);
+    /// let counts = bucketizer.histogram(data.into_iter());
+    /// assert_eq!(counts, vec![2, 1, 1, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn histogram(
+        &self,
+        iter: I,
+    ) -> Vec<usize>
+    where
+        Self: BucketCount,
+    {
+        let mut counts = vec![0; self.num_buckets().unwrap_or(0)];
+        for value in iter {
+            let bucket = self.bucketize(&value);
+            if bucket >= counts.len() {
+                counts.resize(bucket + 1, 0);
+            }
+            counts[bucket] += 1;
+        }
+        counts
+    }
+
+    /// Bucketizes every item of `iter` and returns the cumulative counts, 
+    /// where element `i` is the number of items in buckets `0..=i`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::Bucketize;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![1, 6, 7, 11, 12, 13];
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// assert_eq!(bucketizer.cumulative_histogram(data.into_iter()), vec![1, 3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn cumulative_histogram(
+        &self,
+        iter: I,
+    ) -> Vec<usize>
+    where
+        Self: BucketCount,
+    {
+        cumulative(&self.histogram(iter))
+    }
+
+    /// Bucketizes every item of `iter` and pairs each value with its 
+    /// bucket, preserving the input order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::Bucketize;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![11, 1, 6];
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// let pairs = bucketizer.bucketize_pairs(data.into_iter());
+    ///
+    /// assert_eq!(pairs, vec![(11, 2), (1, 0), (6, 1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn bucketize_pairs(
+        &self,
+        iter: I,
+    ) -> Vec<(T, usize)>
+    {
+        iter.map(|value| (value, self.bucketize(&value)))
+            .collect()
+    }
+
+    /// Bucketizes every item of `iter` into `out`, replacing its previous 
+    /// contents. The buffer's allocation is reused, so calling this 
+    /// repeatedly with the same buffer avoids allocating per batch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::Bucketize;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    /// let mut out = Vec::with_capacity(16);
+    /// let capacity = out.capacity();
+    ///
+    /// bucketizer.bucketize_into(vec![1, 6, 11].into_iter(), &mut out);
+    /// assert_eq!(out, vec![0, 1, 2]);
+    ///
+    /// bucketizer.bucketize_into(vec![25, 3].into_iter(), &mut out);
+    /// assert_eq!(out, vec![5, 0]);
+    /// assert_eq!(out.capacity(), capacity);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn bucketize_into(
+        &self,
+        iter: I,
+        out: &mut Vec<usize>,
+    )
+    {
+        out.clear();
+        out.extend(iter.map(|value| self.bucketize(&value)));
+    }
+}
+
+/// Boxed bucketizers, including `Box<dyn BucketizeSingle<T>>`, expose all 
+/// `Bucketize` methods as well.
+///
+/// ```
+/// use buckets::bucketize::{Bucketize, BucketizeSingle};
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+///
+/// let bucketizer: Box<dyn BucketizeSingle<f64>> = FixedWidthBucketizer::new(5.0, 0.0).boxed();
+///
+/// assert_eq!(bucketizer.bucketize_iter(vec![1.0, 6.0, 11.0].into_iter()), vec![0, 1, 2]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T, I, B> Bucketize<T, I> for Box<B>
+where
+    T: PartialOrd + Copy,
+    I: Iterator<Item = T>,
+    B: BucketizeSingle<T> + ?Sized,
+{}
+
+/// A trait for producing bucket indices as a narrower index type than 
+/// `usize`, such as `u16` for indexing compact arrays.
+///
+/// It is implemented for every `BucketizeSingle`, on top of `bucketize`.
+pub trait BucketizeAs<T, Idx>: BucketizeSingle<T>
+where
+    T: PartialOrd + Copy,
+    Idx: TryFrom<usize>,
+{
+    /// Bucketizes a single value and converts the bucket index into `Idx`, 
+    /// returning the conversion error if the index does not fit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeAs;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(1, 0);
+    ///
+    /// let index: Result<u16, _> = bucketizer.bucketize_as(&42);
+    /// assert_eq!(index, Ok(42u16));
+    ///
+    /// // Bucket 70_000 does not fit in a u16
+    /// let index: Result<u16, _> = bucketizer.bucketize_as(&70_000);
+    /// assert!(index.is_err());
+    /// ```
+    fn bucketize_as(&self, value: &T) -> Result<Idx, Idx::Error> {
+        Idx::try_from(self.bucketize(value))
+    }
+}
+
+impl<T, Idx, B> BucketizeAs<T, Idx> for B
+where
+    T: PartialOrd + Copy,
+    Idx: TryFrom<usize>,
+    B: BucketizeSingle<T> + ?Sized,
+{}
+
+/// A reference-based counterpart to `BucketizeSingle` without the `Copy` 
+/// bound, so that owned, non-`Copy` or unsized values such as `String` or 
+/// `str` can be bucketized without cloning.
+///
+/// It is implemented by the comparison-based bucketizers and by the 
+/// linear, fixed-width, logarithmic, geometric and Gaussian bucketizers, 
+/// which only read values through a reference, and by adapters such as 
+/// `KeyBucketizer`.
+///
+/// ```
+/// use buckets::bucketize::BucketizeRef;
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::bucketizers::linear::LinearBucketizer;
+///
+/// fn bucketize_all<B: BucketizeRef<f64>>(bucketizer: &B, values: &[f64]) -> Vec<usize> {
+///     values.iter().map(|value| bucketizer.bucketize_ref(value)).collect()
+/// }
+///
+/// let values = [1.0, 7.5, 12.0];
+/// assert_eq!(bucketize_all(&LinearBucketizer::new(0.0, 20.0, 4.0), &values), vec![0, 1, 2]);
+/// assert_eq!(bucketize_all(&FixedWidthBucketizer::new(5.0, 0.0), &values), vec![0, 1, 2]);
+/// ```
+pub trait BucketizeRef<T: ?Sized> {
+    fn bucketize_ref(&self, item: &T) -> usize;
 }
+
+/// A trait for bucketizers whose buckets have well-defined numeric 
+/// boundaries, allowing callers to recover the interval covered by 
+/// a given bucket index.
+pub trait BucketBounds<T: PartialOrd + Copy> {
+    /// Returns the inclusive lower and exclusive upper edge of the 
+    /// bucket at `index`, or `None` if `index` is not a valid bucket.
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)>;
+}
+
+/// A trait for bucketizers that can map a value to the center of its 
+/// bucket rather than to the bucket index, e.g. to reconstruct 
+/// representative values from binned data.
+///
+/// The center is the midpoint `low + (high - low) / 2` of the bucket's 
+/// bounds, rounded towards zero for integer types. Values whose bucket 
+/// has no bounds are returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// use buckets::bucketize::BucketCenter;
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::bucketizers::linear::LinearBucketizer;
+/// use buckets::bucketizers::range::RangeBucketizer;
+///
+/// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+///
+/// assert_eq!(bucketizer.bucketize_center(&5.0), 7.5);
+/// assert_eq!(bucketizer.bucketize_center(&9.9), 7.5);
+/// assert_eq!(bucketizer.bucketize_center(&10.0), 12.5);
+///
+/// let bucketizer = FixedWidthBucketizer::new(10, 0);
+/// assert_eq!(bucketizer.bucketize_center(&23), 25);
+///
+/// let bucketizer = RangeBucketizer::new(vec![(0.0, 1.0), (1.0, 100.0)]);
+/// assert_eq!(bucketizer.bucketize_center(&3.0), 50.5);
+/// ```
+pub trait BucketCenter<T>: BucketizeSingle<T> + BucketBounds<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Div<Output = T> + FromPrimitive,
+{
+    /// Returns the center of the bucket `value` is assigned to.
+    fn bucketize_center(&self, value: &T) -> T {
+        self.bucket_bounds(self.bucketize(value))
+            .and_then(|(low, high)| Some(low + (high - low) / T::from_u8(2)?))
+            .unwrap_or(*value)
+    }
+}
+
+/// A trait for bucketizers to report how many buckets they produce, so 
+/// callers can pre-allocate per-bucket storage.
+pub trait BucketCount {
+    /// Returns the number of buckets, or `None` if the bucketizer is 
+    /// unbounded or its bucket count is unknown.
+    fn num_buckets(&self) -> Option<usize>;
+}
+
+/// A trait for bounded bucketizers to report whether a value lies within 
+/// the domain their buckets were designed for, e.g. to detect data drift 
+/// before values are silently clamped into the first or last bucket.
+pub trait BucketDomain<T> {
+    /// Returns `true` if `value` lies within the bucketizer's domain.
+    fn in_domain(&self, value: &T) -> bool;
+}
+
+/// A trait for bucketizing slices in parallel using `rayon`. 
+///
+/// It is implemented for every bucketizer that is `Sync`, which 
+/// includes all of the struct-based bucketizers in this crate.
+#[cfg(feature = "rayon")]
+pub trait ParBucketize<T>: BucketizeSingle<T> + Sync
+where
+    T: PartialOrd + Copy + Sync,
+{
+    /// Bucketizes every element of `data` in parallel. The output 
+    /// order matches the input order, so the result is identical to 
+    /// the one produced by `Bucketize::bucketize_iter`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::ParBucketize;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![1.0, 6.0, 11.0, 25.0];
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// assert_eq!(bucketizer.par_bucketize(&data), vec![0, 1, 2, 5]);
+    /// ```
+    fn par_bucketize(&self, data: &[T]) -> Vec<usize> {
+        data.par_iter()
+            .map(|value| self.bucketize(value))
+            .collect()
+    }
+
+    /// Bucketizes every element of `data` in parallel, writing the bucket 
+    /// of `data[i]` to `out[i]`, so a preallocated buffer can be reused 
+    /// across batches.
+    ///
+    /// Returns `LengthMismatch` without writing anything if `out` and 
+    /// `data` differ in length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{LengthMismatch, ParBucketize};
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let data = vec![1.0, 6.0, 11.0, 25.0];
+    /// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// let mut out = vec![0; 4];
+    /// bucketizer.par_bucketize_into(&data, &mut out).unwrap();
+    /// assert_eq!(out, vec![0, 1, 2, 5]);
+    ///
+    /// let mut out = vec![0; 3];
+    /// assert_eq!(
+    ///     bucketizer.par_bucketize_into(&data, &mut out),
+    ///     Err(LengthMismatch { expected: 4, actual: 3 }),
+    /// );
+    /// ```
+    fn par_bucketize_into(&self, data: &[T], out: &mut [usize]) -> Result<(), LengthMismatch> {
+        if out.len() != data.len() {
+            return Err(LengthMismatch { expected: data.len(), actual: out.len() });
+        }
+
+        out.par_iter_mut()
+            .zip(data.par_iter())
+            .for_each(|(slot, value)| *slot = self.bucketize(value));
+        Ok(())
+    }
+}
+
+/// The error returned when an output buffer does not have one slot per 
+/// input value.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The length of the input, which the buffer must match.
+    pub expected: usize,
+    /// The length of the buffer.
+    pub actual: usize,
+}
+
+#[cfg(feature = "rayon")]
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "output buffer has length {} but the input has length {}", self.actual, self.expected)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Error for LengthMismatch {}
+
+#[cfg(feature = "rayon")]
+impl<T, B> ParBucketize<T> for B
+where
+    T: PartialOrd + Copy + Sync,
+    B: BucketizeSingle<T> + Sync,
+{}
+
+/// Writes one `bucket i: [low, high)` line per bucket of `bucketizer`, 
+/// rendering the last bucket as `bucket i: low+` if it is `open_last`, 
+/// i.e. also receives every value above its range.
+pub(crate) fn fmt_bucket_table<T, B>(
+    f: &mut fmt::Formatter<'_>,
+    bucketizer: &B,
+    num_buckets: usize,
+    open_last: bool,
+) -> fmt::Result
+where
+    T: PartialOrd + Copy + fmt::Display,
+    B: BucketBounds<T>,
+{
+    for index in 0..num_buckets {
+        if index > 0 {
+            writeln!(f)?;
+        }
+        match bucketizer.bucket_bounds(index) {
+            Some((low, _)) if open_last && index + 1 == num_buckets => {
+                write!(f, "bucket {}: {}+", index, low)?
+            }
+            Some((low, high)) => write!(f, "bucket {}: [{}, {})", index, low, high)?,
+            None => write!(f, "bucket {}", index)?,
+        }
+    }
+    Ok(())
+}
+
+/// How a bucketizer assigns values that are not comparable with
+/// themselves, i.e. floating point `NaN`.
+///
+/// `LinearBucketizer` and `QuantileBucketizer` consult their policy before
+/// comparing a value against their boundaries.
+///
+/// The default, `ToLast`, matches how `QuantileBucketizer` placed `NaN`
+/// before policies existed, but changes `LinearBucketizer`, which used to
+/// place `NaN` in the first bucket. Use `ToFirst` to keep that behavior.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NanPolicy {
+    /// Assigns `NaN` to the first bucket.
+    ToFirst,
+    /// Assigns `NaN` to the last bucket.
+    #[default]
+    ToLast,
+    /// Rejects `NaN` with a `NanError`.
+    Error,
+}
+
+impl NanPolicy {
+    /// Returns the bucket a `NaN` value is assigned to among `num_buckets`
+    /// buckets, or `NanError` under `NanPolicy::Error`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{NanError, NanPolicy};
+    ///
+    /// assert_eq!(NanPolicy::ToFirst.resolve(4), Ok(0));
+    /// assert_eq!(NanPolicy::ToLast.resolve(4), Ok(3));
+    /// assert_eq!(NanPolicy::Error.resolve(4), Err(NanError));
+    /// ```
+    pub fn resolve(&self, num_buckets: usize) -> Result<usize, NanError> {
+        match self {
+            NanPolicy::ToFirst => Ok(0),
+            NanPolicy::ToLast => Ok(num_buckets.saturating_sub(1)),
+            NanPolicy::Error => Err(NanError),
+        }
+    }
+}
+
+/// Returns `true` if `value` is not comparable with itself, which for the
+/// primitive types is only the case for floating point `NaN`.
+pub(crate) fn is_nan<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+/// The error returned when a `NaN` value is bucketized under
+/// `NanPolicy::Error`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NanError;
+
+impl fmt::Display for NanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot bucketize a NaN value")
+    }
+}
+
+impl Error for NanError {}
+
+/// Counts of values that fell outside a bucketizer's domain, as returned 
+/// by `BucketizeSingle::bucketize_with_clamp_report`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClampReport {
+    /// The number of values below the lower edge of the first bucket.
+    pub below: usize,
+    /// The number of other out-of-domain values.
+    pub above: usize,
+}
+
+impl ClampReport {
+    /// Returns the total number of clamped values.
+    pub fn total(&self) -> usize {
+        self.below + self.above
+    }
+}
+
+/// Whether a value was clamped into a boundary bucket, as reported by 
+/// `BucketizeSingle::bucketize_detailed`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Clamp {
+    /// The value lies within the bucketizer's domain.
+    InRange,
+    /// The value lies below the lower edge of the first bucket.
+    Below,
+    /// The value lies outside the domain, but not below it.
+    Above,
+}
+
+/// A bucket index together with whether the value was clamped to get 
+/// it, as returned by `BucketizeSingle::bucketize_detailed`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BucketResult {
+    /// The bucket the value was assigned to.
+    pub index: usize,
+    /// Whether, and on which side, the value was clamped.
+    pub clamped: Clamp,
+}
+
+impl BucketResult {
+    /// Returns `true` if the value fell outside the bucketizer's domain.
+    pub fn is_clamped(&self) -> bool {
+        self.clamped != Clamp::InRange
+    }
+}
+
+/// The sentinel bucket `OutlierBuckets` assigns values below the domain to.
+pub const UNDERFLOW_BUCKET: usize = usize::MAX - 1;
+
+/// The sentinel bucket `OutlierBuckets` assigns values above the domain to.
+pub const OVERFLOW_BUCKET: usize = usize::MAX;
+
+/// A bounded bucketizer that routes out-of-domain values to the sentinel 
+/// buckets `UNDERFLOW_BUCKET` and `OVERFLOW_BUCKET`, as returned by 
+/// `BucketizeSingle::with_outlier_buckets`.
+#[derive(Clone, Debug)]
+pub struct OutlierBuckets<B> {
+    bucketizer: B,
+}
+
+impl<B> OutlierBuckets<B> {
+    /// Returns the wrapped bucketizer.
+    pub fn into_inner(self) -> B {
+        self.bucketizer
+    }
+}
+
+impl<T, B> BucketizeSingle<T> for OutlierBuckets<B>
+where
+    T: PartialOrd + Copy,
+    B: BucketizeSingle<T> + BucketDomain<T> + BucketBounds<T>,
+{
+    fn bucketize(&self, value: &T) -> usize {
+        match clamp_of(&self.bucketizer, value) {
+            Clamp::InRange => self.bucketizer.bucketize(value),
+            Clamp::Below => UNDERFLOW_BUCKET,
+            Clamp::Above => OVERFLOW_BUCKET,
+        }
+    }
+}
+
+impl<T, I, B> Bucketize<T, I> for OutlierBuckets<B>
+where
+    T: PartialOrd + Copy,
+    I: Iterator<Item = T>,
+    B: BucketizeSingle<T> + BucketDomain<T> + BucketBounds<T>,
+{}
+
+/// The error returned by constructors that derive their buckets from 
+/// data, such as `from_data`, when there is no data to derive them from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DataError {
+    /// The data was empty, or held no usable values, e.g. only `NaN`.
+    EmptyData,
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataError::EmptyData => write!(f, "cannot compute buckets from empty data"),
+        }
+    }
+}
+
+impl Error for DataError {}
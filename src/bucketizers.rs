@@ -1,5 +1,32 @@
+pub mod chained;
 pub mod custom;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+pub mod doubling;
 pub mod fw;
+#[cfg(feature = "std")]
+pub mod gaussian;
+pub mod geometric;
+#[cfg(feature = "std")]
+pub mod jenks;
+pub mod key;
+#[cfg(feature = "alloc")]
+pub mod labeled;
 pub mod linear;
+#[cfg(feature = "std")]
+pub mod log;
+#[cfg(feature = "std")]
+pub mod memoized;
+pub mod modulo;
+#[cfg(feature = "alloc")]
+pub mod multidim;
+#[cfg(feature = "alloc")]
+pub mod percentile;
+#[cfg(feature = "alloc")]
 pub mod quantile;
+#[cfg(feature = "alloc")]
 pub mod range;
+#[cfg(feature = "std")]
+pub mod robust;
+#[cfg(feature = "std")]
+pub mod streaming_quantile;
@@ -0,0 +1,121 @@
+use crate::bucketize::{BucketCount, Bucketize, BucketizeSingle};
+use crate::bucketizers::custom::CustomBucketizer;
+
+/// A bucketizer that bucketizes values with a first bucketizer and then
+/// remaps the resulting indices with a second bucketizer over `usize`.
+///
+/// This composes e.g. fine-grained fixed width bins with a coarser
+/// regrouping of the index space in a single object.
+///
+/// ```
+/// pub struct ChainedBucketizer<B1, B2> {
+///     first: B1,
+///     second: B2,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChainedBucketizer<B1, B2> {
+    first: B1,
+    second: B2,
+}
+
+impl<B1, B2> ChainedBucketizer<B1, B2> {
+    /// Creates a new `ChainedBucketizer` that feeds the bucket index
+    /// produced by `first` into `second`.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The bucketizer applied to the input values.
+    /// * `second` - The bucketizer applied to the indices produced by `first`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::chained::ChainedBucketizer;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = ChainedBucketizer::new(
+    ///     FixedWidthBucketizer::new(5, 0),
+    ///     RangeBucketizer::new(vec![(0, 2), (2, 10), (10, usize::MAX)]),
+    /// );
+    ///
+    /// assert_eq!(bucketizer.bucketize(&7), 0);
+    /// assert_eq!(bucketizer.bucketize(&12), 1);
+    /// assert_eq!(bucketizer.bucketize(&51), 2);
+    /// ```
+    pub fn new(first: B1, second: B2) -> Self {
+        ChainedBucketizer { first, second }
+    }
+}
+
+impl<B1, F> ChainedBucketizer<B1, CustomBucketizer<usize, F>>
+where
+    F: Fn(&usize) -> usize,
+{
+    /// Creates a new `ChainedBucketizer` that remaps the bucket index
+    /// produced by `first` with the closure `remap`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::chained::ChainedBucketizer;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// // Bins of width 5, merged pairwise into bins of width 10
+    /// let bucketizer = ChainedBucketizer::with_remap(FixedWidthBucketizer::new(5, 0), |index| index / 2);
+    ///
+    /// let binned: Vec<usize> = [0, 4, 5, 9, 10, 19, 20].iter().map(|v| bucketizer.bucketize(v)).collect();
+    ///
+    /// assert_eq!(binned, vec![0, 0, 0, 0, 1, 1, 2]);
+    /// ```
+    pub fn with_remap(first: B1, remap: F) -> Self {
+        ChainedBucketizer { first, second: CustomBucketizer::new(remap) }
+    }
+}
+
+impl<T, B1, B2> BucketizeSingle<T> for ChainedBucketizer<B1, B2>
+where
+    T: PartialOrd + Copy,
+    B1: BucketizeSingle<T>,
+    B2: BucketizeSingle<usize>,
+{
+    /// Bucketizes a single value with the first bucketizer, then bucketizes
+    /// the resulting index with the second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::chained::ChainedBucketizer;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = ChainedBucketizer::new(
+    ///     FixedWidthBucketizer::new(5.0, 0.0),
+    ///     FixedWidthBucketizer::new(2usize, 0),
+    /// );
+    ///
+    /// assert_eq!(bucketizer.bucketize(&12.5), 1);
+    /// assert_eq!(bucketizer.bucketize_iter(vec![1.0, 6.0, 11.0, 16.0, 21.0].into_iter()), vec![0, 0, 1, 1, 2]);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        self.second.bucketize(&self.first.bucketize(value))
+    }
+}
+
+impl<T, B1, B2, I> Bucketize<T, I> for ChainedBucketizer<B1, B2>
+where
+    T: PartialOrd + Copy,
+    B1: BucketizeSingle<T>,
+    B2: BucketizeSingle<usize>,
+    I: Iterator<Item = T>,
+{}
+
+impl<B1, B2: BucketCount> BucketCount for ChainedBucketizer<B1, B2> {
+    fn num_buckets(&self) -> Option<usize> {
+        self.second.num_buckets()
+    }
+}
@@ -1,12 +1,22 @@
-use std::marker::PhantomData;
-use std::iter::Iterator;
-use crate::bucketize::{Bucketize, BucketizeSingle}; 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::any::type_name;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::iter::Iterator;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeRef, BucketizeSingle}; 
 
 /// A bucketizer that allows the caller to 
 /// implement a custom bucketization scheme 
 /// and still expose all underlying `Bucketize` trait 
 /// methods
 ///
+/// Unlike the other bucketizers, `CustomBucketizer` does not implement
+/// `Serialize`/`Deserialize` under the `serde` feature, since the
+/// wrapped closure cannot be serialized.
+///
 /// ```
 /// use std::marker::PhantomData;
 ///
@@ -19,7 +29,7 @@ use crate::bucketize::{Bucketize, BucketizeSingle};
 /// }
 ///
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct CustomBucketizer<T: PartialOrd + Copy, F> 
 where 
     F: Fn(&T) -> usize 
@@ -65,9 +75,39 @@ where
     pub fn new(func: F) -> Self {
         CustomBucketizer { 
             bucketizer: func, 
-            phantom: PhantomData::default() 
+            phantom: PhantomData 
         }
     }
+
+    /// Returns `true` if the bucketization function is monotonic over 
+    /// `samples`, i.e. if bucketizing them in ascending order never yields 
+    /// a lower bucket than the one before. The samples are sorted first, 
+    /// so they may be given in any order.
+    ///
+    /// Only the given samples are checked, so `true` is evidence rather 
+    /// than proof of monotonicity.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::CustomBucketizer;
+    ///
+    /// let samples = vec![12.0, 0.5, 7.0, 25.0, 3.0];
+    ///
+    /// let monotonic = CustomBucketizer::new(|value: &f64| (*value / 5.0) as usize);
+    /// assert!(monotonic.verify_monotonic(&samples));
+    ///
+    /// // Wraps around every 10 units
+    /// let cyclic = CustomBucketizer::new(|value: &f64| (*value as usize % 10) / 5);
+    /// assert!(!cyclic.verify_monotonic(&samples));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn verify_monotonic(&self, samples: &[T]) -> bool {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        sorted
+            .windows(2)
+            .all(|pair| self.bucketize(&pair[0]) <= self.bucketize(&pair[1]))
+    }
 }
 
 /// Implements the `Bucketize` trait and its methods to the 
@@ -113,3 +153,266 @@ where
     T: PartialOrd + Copy,
     I: Iterator<Item = T>,
 {}
+
+impl<T: PartialOrd + Copy, F> BucketizeRef<T> for CustomBucketizer<T, F> 
+where 
+    F: Fn(&T) -> usize,
+{
+    fn bucketize_ref(&self, item: &T) -> usize {
+        (self.bucketizer)(item)
+    }
+}
+
+/// Closures do not implement `Debug`, so `CustomBucketizer` reports the 
+/// type name of its bucketization function instead.
+///
+/// ```
+/// use buckets::bucketizers::custom::CustomBucketizer;
+///
+/// let bucketizer = CustomBucketizer::new(|value: &i32| *value as usize);
+///
+/// assert!(format!("{:?}", bucketizer).starts_with("CustomBucketizer"));
+/// ```
+impl<T: PartialOrd + Copy, F> fmt::Debug for CustomBucketizer<T, F>
+where
+    F: Fn(&T) -> usize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomBucketizer")
+            .field("bucketizer", &type_name::<F>())
+            .finish()
+    }
+}
+
+/// A bucketizer that allows the caller to implement a fallible 
+/// custom bucketization scheme, where the closure returns a `Result` 
+/// instead of panicking or producing a garbage index.
+///
+/// ```
+/// use std::marker::PhantomData;
+///
+/// pub struct TryCustomBucketizer<T: PartialOrd, F> {
+///     bucketizer: F,
+///     phantom: PhantomData<T>
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TryCustomBucketizer<T: PartialOrd + Copy, F> {
+    bucketizer: F,
+    phantom: PhantomData<T>
+}
+
+impl<T: PartialOrd + Copy, F> TryCustomBucketizer<T, F> {
+    /// Creates a new fallible custom bucketizer with a bucketization 
+    /// function of the form `Fn(&T) -> Result<usize, E>`.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::TryCustomBucketizer;
+    ///
+    /// let bucketizer: TryCustomBucketizer<f64, _> = TryCustomBucketizer::new(|value: &f64| {
+    ///     if *value < 0.0 {
+    ///         Err("negative input")
+    ///     } else {
+    ///         Ok((*value / 5.0) as usize)
+    ///     }
+    /// });
+    /// ```
+    pub fn new(func: F) -> Self {
+        TryCustomBucketizer { 
+            bucketizer: func, 
+            phantom: PhantomData 
+        }
+    }
+
+    /// Bucketizes a single value, returning the closure's error if 
+    /// bucketization fails.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::TryCustomBucketizer;
+    ///
+    /// let bucketizer = TryCustomBucketizer::new(|value: &f64| {
+    ///     if *value < 0.0 {
+    ///         Err("negative input")
+    ///     } else {
+    ///         Ok((*value / 5.0) as usize)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(bucketizer.try_bucketize(&7.0), Ok(1));
+    /// assert_eq!(bucketizer.try_bucketize(&-1.0), Err("negative input"));
+    /// ```
+    pub fn try_bucketize<E>(&self, value: &T) -> Result<usize, E> 
+    where 
+        F: Fn(&T) -> Result<usize, E>,
+    {
+        (self.bucketizer)(value)
+    }
+
+    /// Bucketizes every item of `iter`, stopping at and returning the 
+    /// first error.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::TryCustomBucketizer;
+    ///
+    /// let bucketizer = TryCustomBucketizer::new(|value: &f64| {
+    ///     if *value < 0.0 {
+    ///         Err(format!("negative input: {}", value))
+    ///     } else {
+    ///         Ok((*value / 5.0) as usize)
+    ///     }
+    /// });
+    ///
+    /// let clean = vec![1.0, 6.0, 11.0];
+    /// assert_eq!(bucketizer.try_bucketize_iter(clean.into_iter()), Ok(vec![0, 1, 2]));
+    ///
+    /// let dirty = vec![1.0, -6.0, -11.0];
+    /// assert_eq!(
+    ///     bucketizer.try_bucketize_iter(dirty.into_iter()),
+    ///     Err("negative input: -6".to_string()),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_bucketize_iter<I, E>(&self, iter: I) -> Result<Vec<usize>, E> 
+    where 
+        F: Fn(&T) -> Result<usize, E>,
+        I: Iterator<Item = T>,
+    {
+        iter.map(|value| self.try_bucketize(&value)).collect()
+    }
+}
+
+impl<T: PartialOrd + Copy, F> fmt::Debug for TryCustomBucketizer<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryCustomBucketizer")
+            .field("bucketizer", &type_name::<F>())
+            .finish()
+    }
+}
+
+impl<T: PartialOrd + Copy, F> BucketCount for CustomBucketizer<T, F> 
+where 
+    F: Fn(&T) -> usize,
+{
+    /// Returns `None`, since the bucket count of an arbitrary closure is 
+    /// unknown.
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketCount;
+    /// use buckets::bucketizers::custom::CustomBucketizer;
+    ///
+    /// let bucketizer = CustomBucketizer::new(|value: &i32| *value as usize);
+    ///
+    /// assert_eq!(bucketizer.num_buckets(), None);
+    /// ```
+    fn num_buckets(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A bucketizer driven by a stateful `FnMut` closure, for schemes that 
+/// depend on previously seen values, such as a moving threshold.
+///
+/// Bucketizing mutates the closure's state, so it takes `&mut self`. 
+/// `StatefulBucketizer` therefore cannot implement `BucketizeSingle` or 
+/// `Bucketize`, whose methods take `&self`, and exposes `bucketize_mut` 
+/// and `bucketize_iter_mut` instead.
+///
+/// ```
+/// use std::marker::PhantomData;
+///
+/// pub struct StatefulBucketizer<T: PartialOrd, F> {
+///     bucketizer: F,
+///     phantom: PhantomData<T>
+/// }
+/// ```
+#[derive(Clone)]
+pub struct StatefulBucketizer<T: PartialOrd + Copy, F> {
+    bucketizer: F,
+    phantom: PhantomData<T>
+}
+
+impl<T: PartialOrd + Copy, F> StatefulBucketizer<T, F> 
+where 
+    F: FnMut(&T) -> usize,
+{
+    /// Creates a new stateful bucketizer with a bucketization function 
+    /// of the form `FnMut(&T) -> usize`.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::StatefulBucketizer;
+    ///
+    /// let mut count = 0;
+    /// let mut bucketizer = StatefulBucketizer::new(move |_: &f64| {
+    ///     count += 1;
+    ///     count / 10
+    /// });
+    ///
+    /// assert_eq!(bucketizer.bucketize_mut(&1.0), 0);
+    /// ```
+    pub fn new(func: F) -> Self {
+        StatefulBucketizer { 
+            bucketizer: func, 
+            phantom: PhantomData 
+        }
+    }
+
+    /// Bucketizes a single value, updating the closure's state.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::StatefulBucketizer;
+    ///
+    /// // Bucket 1 holds values above the running maximum, i.e. new highs
+    /// let mut high = f64::NEG_INFINITY;
+    /// let mut bucketizer = StatefulBucketizer::new(move |value: &f64| {
+    ///     if *value > high {
+    ///         high = *value;
+    ///         1
+    ///     } else {
+    ///         0
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(bucketizer.bucketize_mut(&5.0), 1);
+    /// assert_eq!(bucketizer.bucketize_mut(&3.0), 0);
+    /// assert_eq!(bucketizer.bucketize_mut(&5.0), 0);
+    /// assert_eq!(bucketizer.bucketize_mut(&8.0), 1);
+    /// ```
+    pub fn bucketize_mut(&mut self, value: &T) -> usize {
+        (self.bucketizer)(value)
+    }
+
+    /// Bucketizes every item of `iter` in order, threading the closure's 
+    /// state through the whole sequence.
+    ///
+    /// ```
+    /// use buckets::bucketizers::custom::StatefulBucketizer;
+    ///
+    /// // Values more than 50% above the running mean are flagged as spikes
+    /// let (mut sum, mut count) = (0.0, 0.0);
+    /// let mut bucketizer = StatefulBucketizer::new(move |value: &f64| {
+    ///     let spike = count > 0.0 && *value > 1.5 * sum / count;
+    ///     sum += value;
+    ///     count += 1.0;
+    ///     spike as usize
+    /// });
+    ///
+    /// let data = vec![10.0, 11.0, 9.0, 20.0, 12.0, 13.0];
+    ///
+    /// assert_eq!(bucketizer.bucketize_iter_mut(data.into_iter()), vec![0, 0, 0, 1, 0, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn bucketize_iter_mut<I>(&mut self, iter: I) -> Vec<usize> 
+    where 
+        I: Iterator<Item = T>,
+    {
+        iter.map(|value| self.bucketize_mut(&value)).collect()
+    }
+}
+
+impl<T: PartialOrd + Copy, F> fmt::Debug for StatefulBucketizer<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatefulBucketizer")
+            .field("bucketizer", &type_name::<F>())
+            .finish()
+    }
+}
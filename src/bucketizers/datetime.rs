@@ -0,0 +1,190 @@
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use crate::bucketize::{BucketCount, Bucketize, BucketizeSingle};
+
+/// The way a `DateTimeBucketizer` assigns timestamps to buckets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateTimeMode {
+    /// 24 buckets, one per UTC hour of the day.
+    HourOfDay,
+    /// 7 buckets, one per UTC day of the week, starting with Monday.
+    DayOfWeek,
+    /// Consecutive windows of `width_millis` milliseconds starting at `epoch`.
+    Window {
+        epoch: DateTime<Utc>,
+        width_millis: i64,
+    },
+}
+
+/// A bucketizer that bins UTC timestamps by hour of day, by day of week,
+/// or into fixed-duration windows from an epoch.
+///
+/// Timestamps from other time zones should be converted with
+/// `with_timezone(&Utc)` first, so buckets always refer to UTC hours and days.
+///
+/// ```
+/// use buckets::bucketizers::datetime::DateTimeMode;
+///
+/// pub struct DateTimeBucketizer {
+///     mode: DateTimeMode,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateTimeBucketizer {
+    mode: DateTimeMode,
+}
+
+impl DateTimeBucketizer {
+    /// Creates a new `DateTimeBucketizer` with 24 buckets, where bucket `h`
+    /// holds timestamps whose UTC hour is `h`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::datetime::DateTimeBucketizer;
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let bucketizer = DateTimeBucketizer::hour_of_day();
+    /// let time: DateTime<Utc> = "2023-06-15T13:45:00Z".parse().unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucketize(&time), 13);
+    /// ```
+    pub fn hour_of_day() -> Self {
+        DateTimeBucketizer { mode: DateTimeMode::HourOfDay }
+    }
+
+    /// Creates a new `DateTimeBucketizer` with 7 buckets, where bucket 0 is
+    /// Monday and bucket 6 is Sunday in UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::datetime::DateTimeBucketizer;
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let bucketizer = DateTimeBucketizer::day_of_week();
+    /// let thursday: DateTime<Utc> = "2023-06-15T13:45:00Z".parse().unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucketize(&thursday), 3);
+    /// ```
+    pub fn day_of_week() -> Self {
+        DateTimeBucketizer { mode: DateTimeMode::DayOfWeek }
+    }
+
+    /// Creates a new `DateTimeBucketizer` that bins timestamps into
+    /// consecutive windows of `width` starting at `epoch`. Windows have
+    /// millisecond precision, and timestamps before `epoch` land in bucket 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The start of the first window.
+    /// * `width` - The duration of each window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is shorter than one millisecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::datetime::DateTimeBucketizer;
+    /// use chrono::{DateTime, Duration, Utc};
+    ///
+    /// let epoch: DateTime<Utc> = "2023-06-15T00:00:00Z".parse().unwrap();
+    /// let bucketizer = DateTimeBucketizer::windows(epoch, Duration::minutes(15));
+    ///
+    /// let time: DateTime<Utc> = "2023-06-15T01:20:00Z".parse().unwrap();
+    /// assert_eq!(bucketizer.bucketize(&time), 5);
+    /// assert_eq!(bucketizer.bucketize(&epoch), 0);
+    /// ```
+    pub fn windows(epoch: DateTime<Utc>, width: Duration) -> Self {
+        let width_millis = width.num_milliseconds();
+        assert!(width_millis > 0, "window width must be at least one millisecond");
+
+        DateTimeBucketizer { mode: DateTimeMode::Window { epoch, width_millis } }
+    }
+
+    /// Returns the mode of the bucketizer.
+    pub fn mode(&self) -> DateTimeMode {
+        self.mode
+    }
+}
+
+impl BucketizeSingle<DateTime<Utc>> for DateTimeBucketizer {
+    /// Bucketizes a single timestamp using the `DateTimeBucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::datetime::DateTimeBucketizer;
+    /// use chrono::{DateTime, FixedOffset, Utc};
+    ///
+    /// let hours = DateTimeBucketizer::hour_of_day();
+    /// let days = DateTimeBucketizer::day_of_week();
+    ///
+    /// // The last second of Sunday and the first second of Monday.
+    /// let sunday: DateTime<Utc> = "2023-01-01T23:59:59Z".parse().unwrap();
+    /// let monday: DateTime<Utc> = "2023-01-02T00:00:00Z".parse().unwrap();
+    /// assert_eq!((hours.bucketize(&sunday), days.bucketize(&sunday)), (23, 6));
+    /// assert_eq!((hours.bucketize(&monday), days.bucketize(&monday)), (0, 0));
+    ///
+    /// // 23:30 on Sunday in New York is already 04:30 on Monday in UTC.
+    /// let local: DateTime<FixedOffset> = "2023-01-01T23:30:00-05:00".parse().unwrap();
+    /// let utc = local.with_timezone(&Utc);
+    /// assert_eq!((hours.bucketize(&utc), days.bucketize(&utc)), (4, 0));
+    ///
+    /// // 00:30 on Monday in Tokyo is still 15:30 on Sunday in UTC.
+    /// let local: DateTime<FixedOffset> = "2023-01-02T00:30:00+09:00".parse().unwrap();
+    /// let utc = local.with_timezone(&Utc);
+    /// assert_eq!((hours.bucketize(&utc), days.bucketize(&utc)), (15, 6));
+    /// ```
+    fn bucketize(&self, value: &DateTime<Utc>) -> usize {
+        match self.mode {
+            DateTimeMode::HourOfDay => value.hour() as usize,
+            DateTimeMode::DayOfWeek => value.weekday().num_days_from_monday() as usize,
+            DateTimeMode::Window { epoch, width_millis } => {
+                if *value < epoch {
+                    return 0;
+                }
+                let elapsed = value.signed_duration_since(epoch).num_milliseconds();
+                (elapsed / width_millis) as usize
+            }
+        }
+    }
+}
+
+impl<I> Bucketize<DateTime<Utc>, I> for DateTimeBucketizer
+where
+    I: Iterator<Item = DateTime<Utc>>,
+{}
+
+impl BucketCount for DateTimeBucketizer {
+    /// Returns 24 for hour of day, 7 for day of week, and `None` for
+    /// windows, which are unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketCount;
+    /// use buckets::bucketizers::datetime::DateTimeBucketizer;
+    /// use chrono::{DateTime, Duration, Utc};
+    ///
+    /// let epoch: DateTime<Utc> = "2023-06-15T00:00:00Z".parse().unwrap();
+    ///
+    /// assert_eq!(DateTimeBucketizer::hour_of_day().num_buckets(), Some(24));
+    /// assert_eq!(DateTimeBucketizer::day_of_week().num_buckets(), Some(7));
+    /// assert_eq!(DateTimeBucketizer::windows(epoch, Duration::hours(1)).num_buckets(), None);
+    /// ```
+    fn num_buckets(&self) -> Option<usize> {
+        match self.mode {
+            DateTimeMode::HourOfDay => Some(24),
+            DateTimeMode::DayOfWeek => Some(7),
+            DateTimeMode::Window { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use core::marker::PhantomData;
+use num_traits::{FromPrimitive, ToPrimitive};
+use crate::bucketize::{BucketBounds, BucketCount, Bucketize, BucketizeSingle};
+
+/// A bucketizer whose buckets double in width, like the size classes of a
+/// memory allocator: `[0, 1)`, `[1, 2)`, `[2, 4)`, `[4, 8)`, and so on.
+///
+/// A value of at least one lands in bucket `floor(log2(value)) + 1`, which
+/// for integers is the position of the highest set bit. Zero, values
+/// below one, negative values and `NaN` all land in bucket 0.
+///
+/// ```
+/// use std::marker::PhantomData;
+///
+/// pub struct DoublingWidthBucketizer<T> {
+///     max_buckets: Option<usize>,
+///     phantom: PhantomData<T>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoublingWidthBucketizer<T> {
+    max_buckets: Option<usize>,
+    phantom: PhantomData<T>,
+}
+
+impl<T> DoublingWidthBucketizer<T> {
+    /// Creates a new, unbounded `DoublingWidthBucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::doubling::DoublingWidthBucketizer;
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::<u64>::new();
+    /// ```
+    pub fn new() -> Self {
+        DoublingWidthBucketizer { max_buckets: None, phantom: PhantomData }
+    }
+
+    /// Caps the bucketizer at `n` buckets, so that any value whose natural
+    /// bucket index is `n` or higher is clamped into the last bucket, `n - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::doubling::DoublingWidthBucketizer;
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::new().with_max_buckets(4);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&5u32), 3);
+    /// assert_eq!(bucketizer.bucketize(&1000u32), 3);
+    /// ```
+    pub fn with_max_buckets(mut self, n: usize) -> Self {
+        self.max_buckets = Some(n);
+        self
+    }
+}
+
+impl<T> Default for DoublingWidthBucketizer<T> {
+    fn default() -> Self {
+        DoublingWidthBucketizer::new()
+    }
+}
+
+impl<T> BucketizeSingle<T> for DoublingWidthBucketizer<T>
+where
+    T: PartialOrd + Copy + ToPrimitive,
+{
+    /// Bucketizes a single value using the `DoublingWidthBucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::doubling::DoublingWidthBucketizer;
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::new();
+    /// assert_eq!(bucketizer.bucketize_slice(&[0, 1, 2, 3, 4, 8]), vec![0, 1, 2, 2, 3, 4]);
+    /// assert_eq!(bucketizer.bucketize(&u64::MAX), 64);
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::new();
+    /// assert_eq!(bucketizer.bucketize(&-5i32), 0);
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::new();
+    /// assert_eq!(bucketizer.bucketize_slice(&[0.5, 1.0, 3.9, 4.0]), vec![0, 1, 2, 3]);
+    /// assert_eq!(bucketizer.bucketize(&-2.5), 0);
+    /// assert_eq!(bucketizer.bucketize(&f64::NAN), 0);
+    /// assert_eq!(bucketizer.bucketize(&1e300), 997);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        // Truncating a value of at least one keeps its highest set bit, so
+        // floats share the integer path unless they are too large for it
+        let bucket_index = match value.to_u128() {
+            Some(whole) => (u128::BITS - whole.leading_zeros()) as usize,
+            None => match value.to_f64() {
+                Some(float) if float > 0.0 => float_exponent(float) + 1,
+                _ => 0,
+            },
+        };
+        match self.max_buckets {
+            Some(n) if bucket_index >= n => n.saturating_sub(1),
+            _ => bucket_index,
+        }
+    }
+}
+
+/// Returns `floor(log2(value))` of a positive, normal `value` from the
+/// exponent bits of its representation.
+fn float_exponent(value: f64) -> usize {
+    ((value.to_bits() >> 52) & 0x7ff) as usize - 1023
+}
+
+/// Returns `2^exponent` as an `f64`, or `None` if it is not finite.
+fn power_of_two(exponent: usize) -> Option<f64> {
+    (exponent <= 1023).then(|| f64::from_bits(((exponent + 1023) as u64) << 52))
+}
+
+impl<T, I> Bucketize<T, I> for DoublingWidthBucketizer<T>
+where
+    T: PartialOrd + Copy + ToPrimitive,
+    I: Iterator<Item = T>,
+{}
+
+impl<T> BucketBounds<T> for DoublingWidthBucketizer<T>
+where
+    T: PartialOrd + Copy + FromPrimitive,
+{
+    /// Returns `[0, 1)` for bucket 0 and `[2^(index - 1), 2^index)` for
+    /// every other bucket, or `None` if `index` is at or above the cap or
+    /// the bounds cannot be represented as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::doubling::DoublingWidthBucketizer;
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::<u8>::new();
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((0, 1)));
+    /// assert_eq!(bucketizer.bucket_bounds(3), Some((4, 8)));
+    /// assert_eq!(bucketizer.bucket_bounds(8), None);
+    ///
+    /// let bucketizer = DoublingWidthBucketizer::<f64>::new();
+    /// assert_eq!(bucketizer.bucket_bounds(1), Some((1.0, 2.0)));
+    /// assert_eq!(bucketizer.bucket_bounds(997), Some((2f64.powi(996), 2f64.powi(997))));
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        if matches!(self.max_buckets, Some(n) if index >= n) {
+            return None;
+        }
+        let low = match index {
+            0 => T::from_u8(0)?,
+            _ => T::from_f64(power_of_two(index - 1)?)?,
+        };
+        let high = T::from_f64(power_of_two(index)?)?;
+        Some((low, high))
+    }
+}
+
+impl<T> BucketCount for DoublingWidthBucketizer<T> {
+    /// Returns `None`, since the buckets are unbounded, unless a cap was
+    /// set with `with_max_buckets`.
+    fn num_buckets(&self) -> Option<usize> {
+        self.max_buckets
+    }
+}
@@ -1,38 +1,34 @@
-use std::ops::{Sub, Div};
-use crate::{bucketize::{Bucketize, BucketizeSingle}, into_usize::IntoUsize};
+use core::fmt;
+use core::ops::{Add, Mul};
+use num_traits::FromPrimitive;
+use crate::{bucketize::{fmt_bucket_table, BucketBounds, BucketCenter, BucketCount, Bucketize, BucketizeRef, BucketizeSingle}, into_usize::TryIntoUsize, numeric::{checked_offset, Numeric}};
 
 /// A generic Fixed-Width Bucketizer Struct used to 
 /// bin data of type T into fixed width buckets
 /// 
 /// ```
-/// use std::ops::{Sub, Div};
-/// use buckets::into_usize::IntoUsize;
+/// use buckets::numeric::Numeric;
 ///
-/// pub struct FixedWidthBucketizer<T: PartialOrd + Sub<Output = T> + Div<Output = T> + IntoUsize> {
+/// pub struct FixedWidthBucketizer<T: Numeric> {
 ///     width: T,
 ///     offset: T,
+///     max_buckets: Option<usize>,
 /// }
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedWidthBucketizer<T> 
 where 
-    T: PartialOrd
-    + Sub<Output = T>
-    + Div<Output = T>
-    + IntoUsize
-    + Copy
+    T: Numeric
 {
     width: T,
     offset: T,
+    max_buckets: Option<usize>,
 }
 
 impl<T> FixedWidthBucketizer<T> 
 where 
-    T: PartialOrd
-    + Sub<Output = T>
-    + Div<Output = T>
-    + IntoUsize
-    + Copy
+    T: Numeric
 {
     /// return a new `FixedWidthBucketizer<T>` instance 
     ///
@@ -54,17 +50,112 @@ where
     ///
     /// ```
     pub fn new(width: T, offset: T) -> Self {
-        FixedWidthBucketizer { width, offset }
+        FixedWidthBucketizer { width, offset, max_buckets: None }
+    }
+
+    /// Caps the bucketizer at `n` buckets, so that any value whose natural
+    /// bucket index is `n` or higher is clamped into the last bucket, `n - 1`.
+    ///
+    /// Without a cap the buckets are unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let uncapped = FixedWidthBucketizer::new(5, 0);
+    /// let capped = FixedWidthBucketizer::new(5, 0).with_max_buckets(4);
+    ///
+    /// assert_eq!(uncapped.bucketize(&1000), 200);
+    /// assert_eq!(capped.bucketize(&1000), 3);
+    /// assert_eq!(capped.bucketize(&12), 2);
+    /// ```
+    pub fn with_max_buckets(mut self, n: usize) -> Self {
+        self.max_buckets = Some(n);
+        self
+    }
+
+    /// Returns the width of each bucket.
+    pub fn width(&self) -> T {
+        self.width
+    }
+}
+
+impl<T> FixedWidthBucketizer<T>
+where
+    T: Numeric + FromPrimitive
+{
+    /// Creates a new `FixedWidthBucketizer` that splits `start..end` into
+    /// `num_buckets` buckets of equal width, with the offset at `start`.
+    ///
+    /// This lays out the same buckets as `LinearBucketizer`, but keeps the
+    /// unbounded upper end of a `FixedWidthBucketizer`: values past `end`
+    /// continue into further buckets instead of being clamped into the
+    /// last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is zero or cannot be represented as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::from_range(10.0, 30.0, 4);
+    ///
+    /// assert_eq!(bucketizer.width(), 5.0);
+    /// assert_eq!(bucketizer.bucketize(&20.0), 2);
+    /// assert_eq!(bucketizer.bucketize(&37.0), 5);
+    /// ```
+    pub fn from_range(start: T, end: T, num_buckets: usize) -> Self {
+        assert!(num_buckets > 0, "at least one bucket is required");
+        let count = T::from_usize(num_buckets)
+            .expect("num_buckets must be representable as T");
+
+        FixedWidthBucketizer::new((end - start) / count, start)
+    }
+}
+
+impl<T> FixedWidthBucketizer<T>
+where
+    T: Numeric + TryIntoUsize + Default
+{
+    /// Bucketizes a single value like `bucketize`, but returns `None`
+    /// instead of a wrapped or truncated index when the bucket index does
+    /// not fit in a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(1u128, 0);
+    /// assert_eq!(bucketizer.checked_bucketize(&42), Some(42));
+    /// assert_eq!(bucketizer.checked_bucketize(&u128::MAX), None);
+    ///
+    /// // A negative width yields negative indices
+    /// let bucketizer = FixedWidthBucketizer::new(-5i64, 0);
+    /// assert_eq!(bucketizer.checked_bucketize(&12), None);
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(1e-300, 0.0);
+    /// assert_eq!(bucketizer.checked_bucketize(&1.0), None);
+    /// ```
+    pub fn checked_bucketize(&self, value: &T) -> Option<usize> {
+        let adjusted_value = checked_offset(*value, self.offset);
+        let bucket_index = (adjusted_value / self.width).try_into_usize()?;
+        match self.max_buckets {
+            Some(n) if bucket_index >= n => Some(n.saturating_sub(1)),
+            _ => Some(bucket_index),
+        }
     }
 }
 
 impl<T> BucketizeSingle<T> for FixedWidthBucketizer<T> 
 where 
-    T: PartialOrd
-    + Sub<Output = T>
-    + Div<Output = T>
-    + IntoUsize 
-    + Copy
+    T: Numeric + Default
 {
     /// Bucketizes a single value using the FixedWidthBucketizer
     ///
@@ -80,19 +171,136 @@ where
     ///
     /// assert_eq!(bucket, 2);
     /// ```
+    ///
+    /// Values below the offset are assigned to bucket 0 instead of
+    /// underflowing, which matters for unsigned types.
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::<u8>::new(5, 10);
+    /// assert_eq!(bucketizer.bucketize(&3), 0);
+    /// assert_eq!(bucketizer.bucketize(&10), 0);
+    ///
+    /// let bucketizer = FixedWidthBucketizer::<u32>::new(5, 10);
+    /// assert_eq!(bucketizer.bucketize(&3), 0);
+    /// assert_eq!(bucketizer.bucketize(&10), 0);
+    /// assert_eq!(bucketizer.bucketize(&15), 1);
+    ///
+    /// let bucketizer = FixedWidthBucketizer::<f64>::new(5.0, 10.0);
+    /// assert_eq!(bucketizer.bucketize(&3.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&10.0), 0);
+    /// ```
     fn bucketize(&self, value: &T) -> usize {
-        let adjusted_value = *value - self.offset;
-        let bucket_index = adjusted_value / self.width;
-        bucket_index.into_usize() 
+        let adjusted_value = checked_offset(*value, self.offset);
+        let bucket_index = (adjusted_value / self.width).into_usize();
+        match self.max_buckets {
+            Some(n) if bucket_index >= n => n.saturating_sub(1),
+            _ => bucket_index,
+        }
     }
 }
 
 impl<T, I> Bucketize<T, I> for FixedWidthBucketizer<T> 
 where 
-    T: PartialOrd 
-    + Sub<Output = T> 
-    + Div<Output = T> 
-    + IntoUsize
-    + Copy,
+    T: Numeric + Default,
     I: Iterator<Item = T>,
 {}
+
+impl<T> BucketizeRef<T> for FixedWidthBucketizer<T>
+where
+    T: Numeric + Default,
+{
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T> BucketBounds<T> for FixedWidthBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive
+{
+    /// Returns the `[low, low + width)` interval of the bucket at `index`.
+    ///
+    /// Unless capped with `with_max_buckets`, a `FixedWidthBucketizer` has 
+    /// no last bucket, so every index has bounds of the same width. `None` 
+    /// is returned for indices at or above the cap, or when `index` cannot 
+    /// be represented as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5, 10);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((10, 15)));
+    /// assert_eq!(bucketizer.bucket_bounds(2), Some((20, 25)));
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        if matches!(self.max_buckets, Some(n) if index >= n) {
+            return None;
+        }
+
+        let low = self.offset + self.width * T::from_usize(index)?;
+        Some((low, low + self.width))
+    }
+}
+
+impl<T> BucketCenter<T> for FixedWidthBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive + Default
+{}
+
+impl<T> BucketCount for FixedWidthBucketizer<T>
+where
+    T: Numeric
+{
+    /// Returns `None`, since fixed width buckets are unbounded, unless a 
+    /// cap was set with `with_max_buckets`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketCount;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// assert_eq!(FixedWidthBucketizer::new(5, 0).num_buckets(), None);
+    /// assert_eq!(FixedWidthBucketizer::new(5, 0).with_max_buckets(4).num_buckets(), Some(4));
+    /// ```
+    fn num_buckets(&self) -> Option<usize> {
+        self.max_buckets
+    }
+}
+
+impl<T> fmt::Display for FixedWidthBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive + fmt::Display
+{
+    /// Renders one line per bucket, with the last bucket rendered as open
+    /// ended. An uncapped bucketizer has no last bucket, so its first
+    /// bucket is followed by a single line covering all others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    /// assert_eq!(bucketizer.to_string(), "bucket 0: [0, 5)\nbucket 1..: 5+");
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0).with_max_buckets(3);
+    /// assert_eq!(bucketizer.to_string(), "bucket 0: [0, 5)\nbucket 1: [5, 10)\nbucket 2: 10+");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max_buckets {
+            Some(n) => fmt_bucket_table(f, self, n, true),
+            None => {
+                fmt_bucket_table(f, self, 1, false)?;
+                write!(f, "\nbucket 1..: {}+", self.offset + self.width)
+            }
+        }
+    }
+}
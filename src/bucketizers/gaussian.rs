@@ -0,0 +1,134 @@
+use std::error::Error;
+use std::fmt;
+use num_traits::Float;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeRef, BucketizeSingle};
+
+/// The error returned when a `GaussianBucketizer` is constructed with an
+/// unusable standard deviation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GaussianError {
+    /// The standard deviation is zero, negative, or not finite, so z-scores
+    /// cannot be computed.
+    InvalidStdDev,
+}
+
+impl fmt::Display for GaussianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GaussianError::InvalidStdDev => {
+                write!(f, "standard deviation must be positive and finite")
+            }
+        }
+    }
+}
+
+impl Error for GaussianError {}
+
+/// A bucketizer that bins values by their z-score, i.e. by how many
+/// standard deviations they lie from a mean.
+///
+/// The `GaussianBucketizer` takes a mean, a standard deviation, and an
+/// ascending list of sigma cut points. With cut points `[-1, 0, 1]`
+/// there are four buckets: below -1σ, [-1σ, 0σ), [0σ, 1σ) and 1σ and above.
+///
+/// ```
+/// use num_traits::Float;
+///
+/// pub struct GaussianBucketizer<T: Float> {
+///     mean: T,
+///     std_dev: T,
+///     cut_points: Vec<T>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaussianBucketizer<T: Float> {
+    mean: T,
+    std_dev: T,
+    cut_points: Vec<T>,
+}
+
+impl<T: Float> GaussianBucketizer<T> {
+    /// Creates a new `GaussianBucketizer` with a specified mean, standard deviation, and sigma cut points.
+    ///
+    /// # Arguments
+    ///
+    /// * `mean` - The mean that z-scores are measured from.
+    /// * `std_dev` - The standard deviation used to scale values into z-scores. Must be positive and finite.
+    /// * `cut_points` - The ascending z-scores at which a new bucket begins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::gaussian::{GaussianBucketizer, GaussianError};
+    ///
+    /// let cut_points = vec![-2.0, -1.0, 0.0, 1.0, 2.0];
+    ///
+    /// assert!(GaussianBucketizer::new(10.0, 2.0, cut_points.clone()).is_ok());
+    /// assert_eq!(
+    ///     GaussianBucketizer::new(10.0, 0.0, cut_points).unwrap_err(),
+    ///     GaussianError::InvalidStdDev,
+    /// );
+    /// ```
+    pub fn new(mean: T, std_dev: T, cut_points: Vec<T>) -> Result<Self, GaussianError> {
+        if !(std_dev > T::zero() && std_dev.is_finite()) {
+            return Err(GaussianError::InvalidStdDev);
+        }
+
+        Ok(GaussianBucketizer { mean, std_dev, cut_points })
+    }
+
+    /// Returns the z-score of `value`.
+    pub fn z_score(&self, value: &T) -> T {
+        (*value - self.mean) / self.std_dev
+    }
+}
+
+impl<T: Float> BucketizeSingle<T> for GaussianBucketizer<T> {
+    /// Bucketizes a single value using the `GaussianBucketizer`.
+    ///
+    /// The value is assigned to the first bucket whose upper cut point is
+    /// greater than its z-score, so a value exactly on a cut point starts
+    /// the next bucket.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::gaussian::GaussianBucketizer;
+    ///
+    /// let cut_points = vec![-2.0, -1.0, 0.0, 1.0, 2.0];
+    /// let bucketizer = GaussianBucketizer::new(10.0, 2.0, cut_points).unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucketize(&10.0), 3); // at the mean
+    /// assert_eq!(bucketizer.bucketize(&12.0), 4); // +1 sigma
+    /// assert_eq!(bucketizer.bucketize(&8.0), 2);  // -1 sigma
+    /// assert_eq!(bucketizer.bucketize(&0.0), 0);  // -5 sigma
+    /// assert_eq!(bucketizer.bucketize(&20.0), 5); // +5 sigma
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        let z_score = self.z_score(value);
+        self.cut_points
+            .iter()
+            .position(|cut_point| z_score < *cut_point)
+            .unwrap_or(self.cut_points.len())
+    }
+}
+
+impl<T, I> Bucketize<T, I> for GaussianBucketizer<T>
+where
+    T: Float,
+    I: Iterator<Item = T>,
+{}
+
+impl<T: Float> BucketizeRef<T> for GaussianBucketizer<T> {
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T: Float> BucketCount for GaussianBucketizer<T> {
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.cut_points.len() + 1)
+    }
+}
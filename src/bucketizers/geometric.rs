@@ -0,0 +1,162 @@
+use core::ops::Mul;
+use crate::{bucketize::{BucketBounds, BucketCount, Bucketize, BucketizeRef, BucketizeSingle}, into_usize::IntoUsize};
+
+/// A bucketizer that places bucket boundaries on a geometric progression.
+///
+/// The `GeometricBucketizer` takes a start value, a growth ratio, and the
+/// number of buckets to create. Bucket `k` begins at `start * ratio^k`, so
+/// each bucket is `ratio` times wider than the previous one.
+///
+/// ```
+/// use std::ops::Mul;
+/// use buckets::into_usize::IntoUsize;
+///
+/// pub struct GeometricBucketizer<T>
+/// where
+///     T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+/// {
+///     start: T,
+///     ratio: T,
+///     num_buckets: usize,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+{
+    start: T,
+    ratio: T,
+    num_buckets: usize,
+}
+
+impl<T> GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+{
+    /// Creates a new `GeometricBucketizer` with a specified start value, growth ratio, and number of buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The lower boundary of the first bucket.
+    /// * `ratio` - The multiplicative factor between consecutive bucket boundaries.
+    /// * `num_buckets` - The number of buckets to create. Larger values are clamped into the last bucket.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::geometric::GeometricBucketizer;
+    ///
+    /// let start = 1.0;
+    /// let ratio = 2.0;
+    /// let num_buckets = 4;
+    ///
+    /// let bucketizer = GeometricBucketizer::new(start, ratio, num_buckets);
+    /// ```
+    pub fn new(start: T, ratio: T, num_buckets: usize) -> Self {
+        GeometricBucketizer {
+            start,
+            ratio,
+            num_buckets,
+        }
+    }
+}
+
+impl<T> BucketizeSingle<T> for GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+{
+    /// Bucketizes a single value using the `GeometricBucketizer`.
+    ///
+    /// Returns the largest `k` such that `start * ratio^k <= value`, clamped
+    /// to `num_buckets - 1`. Values below `start` land in bucket 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::geometric::GeometricBucketizer;
+    ///
+    /// let bucketizer = GeometricBucketizer::new(1.0, 2.0, 4);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&1.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&2.0), 1);
+    /// assert_eq!(bucketizer.bucketize(&4.0), 2);
+    /// assert_eq!(bucketizer.bucketize(&8.0), 3);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&3.0), 1);
+    /// assert_eq!(bucketizer.bucketize(&0.5), 0);
+    /// assert_eq!(bucketizer.bucketize(&1000.0), 3);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        if *value < self.start {
+            return 0;
+        }
+
+        let mut bucket_index = 0;
+        let mut boundary = self.start * self.ratio;
+        while bucket_index + 1 < self.num_buckets && boundary <= *value {
+            bucket_index += 1;
+            boundary = boundary * self.ratio;
+        }
+        bucket_index
+    }
+}
+
+impl<T, I> Bucketize<T, I> for GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+    I: Iterator<Item = T>,
+{}
+
+impl<T> BucketizeRef<T> for GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+{
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T> BucketBounds<T> for GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+{
+    /// Returns the `[start * ratio^index, start * ratio^(index + 1))`
+    /// interval of the bucket at `index`, or `None` for
+    /// `index >= num_buckets`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::geometric::GeometricBucketizer;
+    ///
+    /// let bucketizer = GeometricBucketizer::new(1, 2, 4);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((1, 2)));
+    /// assert_eq!(bucketizer.bucket_bounds(3), Some((8, 16)));
+    /// assert_eq!(bucketizer.bucket_bounds(4), None);
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        if index >= self.num_buckets {
+            return None;
+        }
+
+        let mut low = self.start;
+        for _ in 0..index {
+            low = low * self.ratio;
+        }
+        Some((low, low * self.ratio))
+    }
+}
+
+impl<T> BucketCount for GeometricBucketizer<T>
+where
+    T: Mul<Output = T> + PartialOrd + Copy + IntoUsize,
+{
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.num_buckets)
+    }
+}
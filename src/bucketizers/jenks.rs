@@ -0,0 +1,183 @@
+use std::cmp::Ordering;
+use num_traits::Float;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeRef, BucketizeSingle};
+
+/// The largest number of values the Fisher-Jenks optimization runs on.
+/// Larger datasets are sorted and evenly downsampled to this size first.
+pub const MAX_JENKS_SAMPLES: usize = 4096;
+
+/// A bucketizer that uses Jenks natural breaks, which place class
+/// boundaries so as to minimize the variance within each class.
+///
+/// The breaks are computed once from a dataset with the Fisher-Jenks
+/// algorithm, and values are then bucketized against them like a
+/// `RangeBucketizer` with contiguous ranges.
+///
+/// ```
+/// use num_traits::Float;
+///
+/// pub struct JenksBucketizer<T: Float> {
+///     breaks: Vec<T>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JenksBucketizer<T: Float> {
+    breaks: Vec<T>,
+}
+
+impl<T: Float> JenksBucketizer<T> {
+    /// Creates a new `JenksBucketizer` by computing natural breaks for
+    /// `num_classes` classes from `data`.
+    ///
+    /// The Fisher-Jenks algorithm runs in `O(n² · k)` time and `O(n · k)`
+    /// memory for `n` values and `k` classes. To keep this bounded, inputs
+    /// with more than `MAX_JENKS_SAMPLES` values are sorted and evenly
+    /// downsampled to that size, which approximates the optimal breaks.
+    ///
+    /// `num_classes` is clamped to the number of values, and empty `data`
+    /// produces a single class.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The dataset to compute natural breaks from.
+    /// * `num_classes` - The number of classes to create.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::jenks::JenksBucketizer;
+    ///
+    /// let data = [1.0, 2.0, 3.0, 2.0, 1.0, 100.0, 101.0, 99.0, 100.0];
+    /// let bucketizer = JenksBucketizer::new(&data, 2);
+    ///
+    /// assert_eq!(bucketizer.breaks(), &[99.0]);
+    /// ```
+    pub fn new(data: &[T], num_classes: usize) -> Self {
+        let mut sorted = data.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        if sorted.len() > MAX_JENKS_SAMPLES {
+            let len = sorted.len();
+            sorted = (0..MAX_JENKS_SAMPLES)
+                .map(|i| sorted[i * (len - 1) / (MAX_JENKS_SAMPLES - 1)])
+                .collect();
+        }
+
+        let num_classes = num_classes.min(sorted.len());
+        if num_classes < 2 {
+            return JenksBucketizer { breaks: Vec::new() };
+        }
+
+        JenksBucketizer { breaks: fisher_jenks(&sorted, num_classes) }
+    }
+
+    /// Returns the lower bound of every class after the first, in
+    /// ascending order.
+    pub fn breaks(&self) -> &[T] {
+        &self.breaks
+    }
+}
+
+/// Computes the lower bound of classes `2..=num_classes` for sorted
+/// `values`, with `2 <= num_classes <= values.len()`.
+fn fisher_jenks<T: Float>(values: &[T], num_classes: usize) -> Vec<T> {
+    let n = values.len();
+
+    // lower_limits[l][j] is the 1-based index of the first value of class
+    // `j` in the optimal `j`-class split of the first `l` values, and
+    // variances[l][j] is that split's total within-class variance.
+    let mut lower_limits = vec![vec![0usize; num_classes + 1]; n + 1];
+    let mut variances = vec![vec![T::infinity(); num_classes + 1]; n + 1];
+    for class in 1..=num_classes {
+        lower_limits[1][class] = 1;
+        variances[1][class] = T::zero();
+    }
+
+    for l in 2..=n {
+        let mut sum = T::zero();
+        let mut sum_squares = T::zero();
+        let mut count = T::zero();
+        let mut variance = T::zero();
+
+        for m in 1..=l {
+            let lower = l - m + 1;
+            let value = values[lower - 1];
+            sum = sum + value;
+            sum_squares = sum_squares + value * value;
+            count = count + T::one();
+            variance = sum_squares - sum * sum / count;
+
+            let previous = lower - 1;
+            if previous != 0 {
+                for class in 2..=num_classes {
+                    let candidate = variance + variances[previous][class - 1];
+                    if variances[l][class] >= candidate {
+                        lower_limits[l][class] = lower;
+                        variances[l][class] = candidate;
+                    }
+                }
+            }
+        }
+
+        lower_limits[l][1] = 1;
+        variances[l][1] = variance;
+    }
+
+    let mut breaks = vec![T::zero(); num_classes - 1];
+    let mut end = n;
+    for class in (2..=num_classes).rev() {
+        let lower = lower_limits[end][class];
+        breaks[class - 2] = values[lower - 1];
+        end = lower - 1;
+    }
+    breaks
+}
+
+impl<T: Float> BucketizeSingle<T> for JenksBucketizer<T> {
+    /// Bucketizes a single value using the `JenksBucketizer`.
+    ///
+    /// The value is assigned to the last class whose lower bound is less
+    /// than or equal to it. Values below the data's range land in the
+    /// first class and values above it in the last class.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::jenks::JenksBucketizer;
+    ///
+    /// let data = [1.0, 2.0, 3.0, 2.0, 1.0, 100.0, 101.0, 99.0, 100.0];
+    /// let bucketizer = JenksBucketizer::new(&data, 2);
+    ///
+    /// let binned: Vec<usize> = data.iter().map(|v| bucketizer.bucketize(v)).collect();
+    /// assert_eq!(binned, vec![0, 0, 0, 0, 0, 1, 1, 1, 1]);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&-50.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&500.0), 1);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        self.breaks
+            .iter()
+            .position(|lower_bound| value < lower_bound)
+            .unwrap_or(self.breaks.len())
+    }
+}
+
+impl<T, I> Bucketize<T, I> for JenksBucketizer<T>
+where
+    T: Float,
+    I: Iterator<Item = T>,
+{}
+
+impl<T: Float> BucketizeRef<T> for JenksBucketizer<T> {
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T: Float> BucketCount for JenksBucketizer<T> {
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.breaks.len() + 1)
+    }
+}
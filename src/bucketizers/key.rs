@@ -0,0 +1,68 @@
+use core::any::type_name;
+use core::fmt;
+use crate::bucketize::{BucketizeRef, BucketizeSingle};
+
+/// A bucketizer adapter that extracts a `Copy` key from a borrowed value 
+/// and bucketizes the key with an inner bucketizer.
+///
+/// Because the value is only read through a reference, `KeyBucketizer` 
+/// implements `BucketizeRef` for non-`Copy` and unsized types, e.g. to 
+/// bucketize strings by length without cloning them.
+///
+/// ```
+/// pub struct KeyBucketizer<B, F> {
+///     bucketizer: B,
+///     key: F,
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyBucketizer<B, F> {
+    bucketizer: B,
+    key: F,
+}
+
+impl<B, F> KeyBucketizer<B, F> {
+    /// Creates a new `KeyBucketizer` that bucketizes `key(value)` with 
+    /// `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeRef;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::key::KeyBucketizer;
+    ///
+    /// let bucketizer = KeyBucketizer::new(FixedWidthBucketizer::new(5usize, 0), |s: &str| s.len());
+    ///
+    /// let words = vec![String::from("tiny"), String::from("hello world")];
+    /// let binned: Vec<usize> = words.iter().map(|word| bucketizer.bucketize_ref(word.as_str())).collect();
+    ///
+    /// assert_eq!(binned, vec![0, 2]);
+    /// // The strings were never cloned or moved
+    /// assert_eq!(words.len(), 2);
+    /// ```
+    pub fn new(bucketizer: B, key: F) -> Self {
+        KeyBucketizer { bucketizer, key }
+    }
+}
+
+impl<T, K, B, F> BucketizeRef<T> for KeyBucketizer<B, F>
+where
+    T: ?Sized,
+    K: PartialOrd + Copy,
+    B: BucketizeSingle<K>,
+    F: Fn(&T) -> K,
+{
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketizer.bucketize(&(self.key)(item))
+    }
+}
+
+impl<B: fmt::Debug, F> fmt::Debug for KeyBucketizer<B, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyBucketizer")
+            .field("bucketizer", &self.bucketizer)
+            .field("key", &type_name::<F>())
+            .finish()
+    }
+}
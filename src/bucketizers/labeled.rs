@@ -0,0 +1,161 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use core::fmt::Display;
+use crate::bucketize::{BucketBounds, BucketCount, Bucketize, BucketizeSingle};
+
+/// A bucketizer adapter that renders bucket assignments as
+/// human-readable labels instead of numeric indices.
+///
+/// Labels are taken from an optional list of custom labels, and
+/// otherwise generated from the inner bucketizer's `BucketBounds` as
+/// `"low-high"`. The last bucket of a bounded bucketizer also receives
+/// every value above its range, so it is rendered as `"low+"`.
+///
+/// ```
+/// pub struct LabeledBucketizer<B> {
+///     bucketizer: B,
+///     labels: Option<Vec<String>>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LabeledBucketizer<B> {
+    bucketizer: B,
+    labels: Option<Vec<String>>,
+}
+
+impl<B> LabeledBucketizer<B> {
+    /// Creates a new `LabeledBucketizer` that generates labels from the
+    /// bucket bounds of `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::labeled::LabeledBucketizer;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LabeledBucketizer::new(LinearBucketizer::new(0.0, 20.0, 4.0));
+    ///
+    /// assert_eq!(bucketizer.label(&7.0), "5-10");
+    /// ```
+    pub fn new(bucketizer: B) -> Self {
+        LabeledBucketizer { bucketizer, labels: None }
+    }
+
+    /// Creates a new `LabeledBucketizer` that uses `labels[i]` as the label
+    /// of bucket `i`. Buckets without a custom label fall back to labels
+    /// generated from the bucket bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::labeled::LabeledBucketizer;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let ranges = vec![(0, 5), (5, 10), (10, 20), (20, std::i32::MAX)];
+    /// let labels = vec!["low".to_string(), "mid".to_string()];
+    ///
+    /// let bucketizer = LabeledBucketizer::with_labels(RangeBucketizer::new(ranges), labels);
+    ///
+    /// assert_eq!(bucketizer.label(&3), "low");
+    /// assert_eq!(bucketizer.label(&7), "mid");
+    /// assert_eq!(bucketizer.label(&12), "10-20");
+    /// ```
+    pub fn with_labels(bucketizer: B, labels: Vec<String>) -> Self {
+        LabeledBucketizer { bucketizer, labels: Some(labels) }
+    }
+
+    /// Bucketizes `value` with the inner bucketizer and returns the label
+    /// of the resulting bucket.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::labeled::LabeledBucketizer;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let ranges = vec![(0, 5), (5, 10), (10, 20), (20, std::i32::MAX)];
+    /// let bucketizer = LabeledBucketizer::new(RangeBucketizer::new(ranges));
+    ///
+    /// assert_eq!(bucketizer.label(&3), "0-5");
+    /// assert_eq!(bucketizer.label(&7), "5-10");
+    /// assert_eq!(bucketizer.label(&25), "20+");
+    /// ```
+    pub fn label<T>(&self, value: &T) -> String
+    where
+        T: PartialOrd + Copy + Display,
+        B: BucketizeSingle<T> + BucketBounds<T>,
+    {
+        let index = self.bucketizer.bucketize(value);
+
+        if let Some(label) = self.labels.as_ref().and_then(|labels| labels.get(index)) {
+            return label.clone();
+        }
+
+        match self.bucketizer.bucket_bounds(index) {
+            Some((low, _)) if self.bucketizer.bucket_bounds(index + 1).is_none() => {
+                format!("{}+", low)
+            }
+            Some((low, high)) => format!("{}-{}", low, high),
+            None => index.to_string(),
+        }
+    }
+
+    /// Labels every item of `iter`, preserving order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::labeled::LabeledBucketizer;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let ranges = vec![(0, 5), (5, 10), (10, 20), (20, std::i32::MAX)];
+    /// let bucketizer = LabeledBucketizer::new(RangeBucketizer::new(ranges));
+    ///
+    /// let labels = bucketizer.labeled_iter(vec![1, 6, 11, 25].into_iter());
+    ///
+    /// assert_eq!(labels, vec!["0-5", "5-10", "10-20", "20+"]);
+    /// ```
+    pub fn labeled_iter<T, I>(&self, iter: I) -> Vec<String>
+    where
+        T: PartialOrd + Copy + Display,
+        B: BucketizeSingle<T> + BucketBounds<T>,
+        I: Iterator<Item = T>,
+    {
+        iter.map(|value| self.label(&value)).collect()
+    }
+}
+
+impl<T, B> BucketizeSingle<T> for LabeledBucketizer<B>
+where
+    T: PartialOrd + Copy,
+    B: BucketizeSingle<T>,
+{
+    /// Bucketizes a single value with the inner bucketizer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::labeled::LabeledBucketizer;
+    ///
+    /// let bucketizer = LabeledBucketizer::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// assert_eq!(bucketizer.bucketize(&12), 2);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        self.bucketizer.bucketize(value)
+    }
+}
+
+impl<T, B, I> Bucketize<T, I> for LabeledBucketizer<B>
+where
+    T: PartialOrd + Copy,
+    B: BucketizeSingle<T>,
+    I: Iterator<Item = T>,
+{}
+
+impl<B: BucketCount> BucketCount for LabeledBucketizer<B> {
+    fn num_buckets(&self) -> Option<usize> {
+        self.bucketizer.num_buckets()
+    }
+}
@@ -1,6 +1,42 @@
-use std::ops::{Sub, Div};
-use crate::{bucketize::{Bucketize, BucketizeSingle}, into_usize::IntoUsize};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::ops::{Add, Mul};
+use num_traits::FromPrimitive;
+use crate::{bucketize::{fmt_bucket_table, is_nan, BucketBounds, BucketCenter, BucketCount, BucketDomain, Bucketize, BucketizeRef, BucketizeSingle, DataError, NanError, NanPolicy}, into_usize::TryIntoUsize, numeric::{checked_offset, Numeric}};
 
+/// The reasons the parameters of a `LinearBucketizer` can be rejected by
+/// `LinearBucketizer::try_new` or `LinearBucketizerBuilder::build`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinearError {
+    /// The number of buckets is less than one.
+    ZeroBuckets,
+    /// `end` is not greater than `start`.
+    EmptyRange,
+    /// The builder was not given the named parameter.
+    MissingParameter(&'static str),
+    /// The number of buckets cannot be represented as a `T`.
+    TooManyBuckets,
+    /// The bucket width is not positive, e.g. for more integer buckets
+    /// than there are integers from `start` to `end`.
+    ZeroWidth,
+}
+
+impl fmt::Display for LinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearError::ZeroBuckets => write!(f, "at least one bucket is required"),
+            LinearError::EmptyRange => write!(f, "the range end is not greater than its start"),
+            LinearError::MissingParameter(name) => write!(f, "the {} parameter was not set", name),
+            LinearError::TooManyBuckets => write!(f, "the number of buckets cannot be represented"),
+            LinearError::ZeroWidth => write!(f, "the range is too narrow for the number of buckets"),
+        }
+    }
+}
+
+impl Error for LinearError {}
 
 /// A bucketizer that uses linearly spaced buckets to bucketize data.
 ///
@@ -9,30 +45,32 @@ use crate::{bucketize::{Bucketize, BucketizeSingle}, into_usize::IntoUsize};
 /// corresponding bucket based on its position in the linearly spaced range.
 ///
 /// ```
-/// use std::ops::{Sub, Div, Deref};
-/// use buckets::into_usize::IntoUsize;
-/// pub struct LinearBucketizer<T> 
-/// where 
-///     T: PartialOrd + Sub<Output = T> + Div<Output = T> + Copy + IntoUsize,
-/// {
+/// use buckets::bucketize::NanPolicy;
+/// use buckets::numeric::Numeric;
+///
+/// pub struct LinearBucketizer<T: Numeric> {
 ///     start: T,
 ///     num_buckets: usize,
-///     bucket_width: T
+///     bucket_width: T,
+///     nan_policy: NanPolicy,
 /// }
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearBucketizer<T> 
 where 
-    T: PartialOrd + Sub<Output = T> + Div<Output = T> + Copy + IntoUsize,
+    T: Numeric,
 {
     start: T,
     num_buckets: usize,
     bucket_width: T,
+    #[cfg_attr(feature = "serde", serde(default))]
+    nan_policy: NanPolicy,
 }
 
 impl<T> LinearBucketizer<T> 
 where 
-    T: PartialOrd + Sub<Output = T> + Div<Output = T> + Copy + IntoUsize,
+    T: Numeric + Default,
 {
     /// Creates a new `LinearBucketizer` with a specified start value, end value, and number of buckets.
     ///
@@ -54,6 +92,12 @@ where
     /// let bucketizer = LinearBucketizer::new(start, end, num_buckets);
     ///
     /// ```
+    ///
+    /// The parameters are not validated. Zero buckets divide by zero when
+    /// computing the bucket width, which panics for integers, and leave a
+    /// bucketizer that panics on every call to `bucketize`. An `end` that is
+    /// not greater than `start` produces a zero or negative bucket width.
+    /// Use `try_new` to reject both cases up front.
     pub fn new(start: T, end: T, num_buckets: T) -> Self {
         let bucket_width = (end - start) / num_buckets;
         let num_buckets = num_buckets.into_usize();
@@ -62,17 +106,452 @@ where
             start,
             num_buckets,
             bucket_width,
+            nan_policy: NanPolicy::default(),
+        }
+    }
+
+    /// Creates a new `LinearBucketizer` like `new`, but returns an error
+    /// instead of building an unusable bucketizer when `num_buckets` is
+    /// less than one, `end` is not greater than `start`, or the bucket
+    /// width truncates to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::linear::{LinearBucketizer, LinearError};
+    ///
+    /// assert!(LinearBucketizer::try_new(0.0, 20.0, 4.0).is_ok());
+    ///
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(0.0, 20.0, 0.0).unwrap_err(),
+    ///     LinearError::ZeroBuckets,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(0, 20, 0).unwrap_err(),
+    ///     LinearError::ZeroBuckets,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(0, 20, -4).unwrap_err(),
+    ///     LinearError::ZeroBuckets,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(0.0, 20.0, -4.0).unwrap_err(),
+    ///     LinearError::ZeroBuckets,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(20.0, 20.0, 4.0).unwrap_err(),
+    ///     LinearError::EmptyRange,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(20.0, 0.0, 4.0).unwrap_err(),
+    ///     LinearError::EmptyRange,
+    /// );
+    ///
+    /// // Four integer buckets do not fit between 0 and 3
+    /// assert_eq!(
+    ///     LinearBucketizer::try_new(0, 3, 4).unwrap_err(),
+    ///     LinearError::ZeroWidth,
+    /// );
+    /// ```
+    pub fn try_new(start: T, end: T, num_buckets: T) -> Result<Self, LinearError> {
+        // Converting a negative count into a usize would wrap, so rule out
+        // non-positive counts in T before checking the whole bucket count
+        let positive = num_buckets.partial_cmp(&T::default()) == Some(Ordering::Greater);
+        if !positive || num_buckets.into_usize() == 0 {
+            return Err(LinearError::ZeroBuckets);
+        }
+        if start.partial_cmp(&end) != Some(Ordering::Less) {
+            return Err(LinearError::EmptyRange);
+        }
+
+        let bucketizer = LinearBucketizer::new(start, end, num_buckets);
+        if bucketizer.bucket_width.partial_cmp(&T::default()) != Some(Ordering::Greater) {
+            return Err(LinearError::ZeroWidth);
+        }
+
+        Ok(bucketizer)
+    }
+
+    /// Sets how `NaN` values are bucketized. Defaults to `NanPolicy::ToLast`.
+    ///
+    /// Earlier versions placed `NaN` in the first bucket, which
+    /// `NanPolicy::ToFirst` restores.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, NanPolicy};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    /// assert_eq!(bucketizer.bucketize(&f64::NAN), 3);
+    ///
+    /// let bucketizer = bucketizer.with_nan_policy(NanPolicy::ToFirst);
+    /// assert_eq!(bucketizer.bucketize(&f64::NAN), 0);
+    /// ```
+    pub fn with_nan_policy(mut self, nan_policy: NanPolicy) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Bucketizes a single value like `bucketize`, but returns `NanError`
+    /// instead of panicking for `NaN` under `NanPolicy::Error`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{NanError, NanPolicy};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0).with_nan_policy(NanPolicy::Error);
+    ///
+    /// assert_eq!(bucketizer.try_bucketize(&7.0), Ok(1));
+    /// assert_eq!(bucketizer.try_bucketize(&f64::NAN), Err(NanError));
+    /// ```
+    pub fn try_bucketize(&self, value: &T) -> Result<usize, NanError> {
+        if is_nan(value) {
+            return self.nan_policy.resolve(self.num_buckets);
+        }
+
+        let offset = checked_offset(*value, self.start);
+        let bucket_index = (offset / self.bucket_width).into_usize();
+        if bucket_index < self.num_buckets {
+            Ok(bucket_index)
+        } else {
+            Ok(self.num_buckets - 1)
+        }
+    }
+}
+
+impl<T> LinearBucketizer<T>
+where
+    T: Numeric + TryIntoUsize + Default,
+{
+    /// Bucketizes a single value like `bucketize`, but returns `None`
+    /// instead of a wrapped index when the bucket index is negative, e.g.
+    /// for a negative bucket width, and for `NaN` under `NanPolicy::Error`.
+    /// Indices past the last bucket are still clamped into it, even if
+    /// they do not fit in a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    /// assert_eq!(bucketizer.checked_bucketize(&7.0), Some(1));
+    /// assert_eq!(bucketizer.checked_bucketize(&1e300), Some(3));
+    ///
+    /// // An end below the start yields negative indices
+    /// let bucketizer = LinearBucketizer::new(0, -20, 4);
+    /// assert_eq!(bucketizer.checked_bucketize(&12), None);
+    /// ```
+    pub fn checked_bucketize(&self, value: &T) -> Option<usize> {
+        if is_nan(value) {
+            return self.nan_policy.resolve(self.num_buckets).ok();
+        }
+
+        let offset = checked_offset(*value, self.start);
+        let last = self.num_buckets.saturating_sub(1);
+        let quotient = offset / self.bucket_width;
+        match quotient.try_into_usize() {
+            Some(bucket_index) => Some(bucket_index.min(last)),
+            None if quotient > T::default() => Some(last),
+            None => None,
+        }
+    }
+}
+
+impl<T> LinearBucketizer<T>
+where
+    T: Numeric + Add<Output = T>,
+{
+    /// Returns a new `LinearBucketizer` whose range starts at `start + delta`,
+    /// with the same bucket width and number of buckets.
+    ///
+    /// This is useful for sliding windows, where the origin moves but the
+    /// bucket layout stays the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    /// let shifted = bucketizer.shift(5.0);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&12.0), 2);
+    /// assert_eq!(shifted.bucketize(&12.0), 1);
+    /// assert_eq!(shifted.bucketize(&24.0), 3);
+    /// ```
+    pub fn shift(&self, delta: T) -> LinearBucketizer<T> {
+        LinearBucketizer {
+            start: self.start + delta,
+            num_buckets: self.num_buckets,
+            bucket_width: self.bucket_width,
+            nan_policy: self.nan_policy,
+        }
+    }
+}
+
+impl<T> LinearBucketizer<T>
+where
+    T: Numeric + FromPrimitive + Default,
+{
+    /// Creates a new `LinearBucketizer` with `num_buckets` equally spaced
+    /// buckets spanning the minimum to the maximum of `data`. `NaN` values
+    /// are ignored when scanning for the range.
+    ///
+    /// If every value is equal, there is no range to divide, so a single
+    /// bucket of width one starting at that value is created instead.
+    /// Likewise, if the span is too narrow to divide into `num_buckets`
+    /// integer buckets, the buckets are given a width of one.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values whose range the buckets should span.
+    /// * `num_buckets` - The number of equally spaced buckets to create.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::EmptyData` if `data` has no comparable values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is 0, or if `num_buckets` cannot be
+    /// represented as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketBounds, BucketCount, BucketizeSingle, DataError};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::from_data(&[3.0, 7.0, 11.0], 4).unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((3.0, 5.0)));
+    /// assert_eq!(bucketizer.bucket_bounds(3), Some((9.0, 11.0)));
+    /// assert_eq!(bucketizer.bucketize_slice(&[3.0, 7.0, 11.0]), vec![0, 2, 3]);
+    ///
+    /// let bucketizer = LinearBucketizer::from_data(&[5, 5, 5], 4).unwrap();
+    ///
+    /// assert_eq!(bucketizer.num_buckets(), Some(1));
+    /// assert_eq!(bucketizer.bucketize_slice(&[5, 5, 9]), vec![0, 0, 0]);
+    ///
+    /// // Four buckets do not fit between 0 and 3 at a whole width
+    /// let bucketizer = LinearBucketizer::from_data(&[0, 3], 4).unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((0, 1)));
+    /// assert_eq!(bucketizer.bucketize_slice(&[0, 1, 2, 3]), vec![0, 1, 2, 3]);
+    ///
+    /// assert_eq!(LinearBucketizer::<f64>::from_data(&[], 4).unwrap_err(), DataError::EmptyData);
+    /// assert_eq!(LinearBucketizer::from_data(&[f64::NAN], 4).unwrap_err(), DataError::EmptyData);
+    /// ```
+    pub fn from_data(data: &[T], num_buckets: usize) -> Result<Self, DataError> {
+        assert!(num_buckets > 0, "num_buckets must be at least 1");
+
+        let mut values = data.iter().filter(|value| !is_nan(*value));
+        let first = *values.next().ok_or(DataError::EmptyData)?;
+        let (min, max) = values.fold((first, first), |(min, max), &value| {
+            (
+                if value < min { value } else { min },
+                if value > max { value } else { max },
+            )
+        });
+
+        let one = T::from_usize(1).expect("1 must be representable");
+        if min == max {
+            return Ok(LinearBucketizer {
+                start: min,
+                num_buckets: 1,
+                bucket_width: one,
+                nan_policy: NanPolicy::default(),
+            });
         }
+
+        let count = T::from_usize(num_buckets).expect("num_buckets must be representable");
+        let bucket_width = match (max - min) / count {
+            // Integer division truncates a span narrower than the count to 0
+            width if width.partial_cmp(&T::default()) != Some(Ordering::Greater) => one,
+            width => width,
+        };
+        Ok(LinearBucketizer {
+            start: min,
+            num_buckets,
+            bucket_width,
+            nan_policy: NanPolicy::default(),
+        })
+    }
+}
+
+impl<T> LinearBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive,
+{
+    /// Returns the `num_buckets + 1` bucket edges from `start` to `end`,
+    /// e.g. for labelling the axis of a plot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` cannot be represented as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// assert_eq!(bucketizer.edges(), vec![0.0, 5.0, 10.0, 15.0, 20.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn edges(&self) -> Vec<T> {
+        (0..=self.num_buckets)
+            .map(|index| {
+                let index = T::from_usize(index).expect("num_buckets must be representable as T");
+                self.start + self.bucket_width * index
+            })
+            .collect()
+    }
+}
+
+/// A builder for `LinearBucketizer` that takes the number of buckets as a
+/// `usize` rather than as a `T`.
+///
+/// The bucket width is computed by converting the bucket count into `T`,
+/// so `T` must implement `FromPrimitive`. This holds for all primitive
+/// integer and float types.
+///
+/// ```
+/// pub struct LinearBucketizerBuilder<T> {
+///     start: Option<T>,
+///     end: Option<T>,
+///     num_buckets: Option<usize>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LinearBucketizerBuilder<T> {
+    start: Option<T>,
+    end: Option<T>,
+    num_buckets: Option<usize>,
+}
+
+impl<T> Default for LinearBucketizerBuilder<T> {
+    fn default() -> Self {
+        LinearBucketizerBuilder {
+            start: None,
+            end: None,
+            num_buckets: None,
+        }
+    }
+}
+
+impl<T> LinearBucketizerBuilder<T>
+where
+    T: Numeric + FromPrimitive + Default,
+{
+    /// Creates a new, empty `LinearBucketizerBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the start value of the linearly spaced range.
+    pub fn start(mut self, start: T) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the end value of the linearly spaced range.
+    pub fn end(mut self, end: T) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Sets the number of equally spaced buckets to create.
+    pub fn num_buckets(mut self, num_buckets: usize) -> Self {
+        self.num_buckets = Some(num_buckets);
+        self
+    }
+
+    /// Builds the `LinearBucketizer`, validating its parameters like
+    /// `LinearBucketizer::try_new`.
+    ///
+    /// Returns `LinearError::MissingParameter` if the start, end, or number
+    /// of buckets was not set, `LinearError::ZeroBuckets` if the number of
+    /// buckets is 0, `LinearError::EmptyRange` if `end` is not greater than
+    /// `start`, `LinearError::TooManyBuckets` if the number of buckets
+    /// cannot be represented as a `T`, and `LinearError::ZeroWidth` if the
+    /// bucket width truncates to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::linear::{LinearBucketizer, LinearBucketizerBuilder, LinearError};
+    ///
+    /// let built = LinearBucketizerBuilder::new()
+    ///     .start(0.0)
+    ///     .end(20.0)
+    ///     .num_buckets(4)
+    ///     .build()
+    ///     .unwrap();
+    /// let constructed = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// for value in [-3.0, 0.0, 4.9, 5.0, 12.5, 19.9, 25.0] {
+    ///     assert_eq!(built.bucketize(&value), constructed.bucketize(&value));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     LinearBucketizerBuilder::<f64>::new().start(0.0).build().unwrap_err(),
+    ///     LinearError::MissingParameter("end"),
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizerBuilder::new().start(0).end(20).num_buckets(0).build().unwrap_err(),
+    ///     LinearError::ZeroBuckets,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizerBuilder::new().start(20).end(0).num_buckets(4).build().unwrap_err(),
+    ///     LinearError::EmptyRange,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizerBuilder::<u8>::new().start(0).end(20).num_buckets(300).build().unwrap_err(),
+    ///     LinearError::TooManyBuckets,
+    /// );
+    /// assert_eq!(
+    ///     LinearBucketizerBuilder::new().start(0).end(3).num_buckets(4).build().unwrap_err(),
+    ///     LinearError::ZeroWidth,
+    /// );
+    /// ```
+    pub fn build(self) -> Result<LinearBucketizer<T>, LinearError> {
+        let start = self.start.ok_or(LinearError::MissingParameter("start"))?;
+        let end = self.end.ok_or(LinearError::MissingParameter("end"))?;
+        let num_buckets = self.num_buckets.ok_or(LinearError::MissingParameter("num_buckets"))?;
+        if num_buckets == 0 {
+            return Err(LinearError::ZeroBuckets);
+        }
+        if start.partial_cmp(&end) != Some(Ordering::Less) {
+            return Err(LinearError::EmptyRange);
+        }
+        let count = T::from_usize(num_buckets).ok_or(LinearError::TooManyBuckets)?;
+        let bucket_width = (end - start) / count;
+        if bucket_width.partial_cmp(&T::default()) != Some(Ordering::Greater) {
+            return Err(LinearError::ZeroWidth);
+        }
+
+        Ok(LinearBucketizer {
+            start,
+            num_buckets,
+            bucket_width,
+            nan_policy: NanPolicy::default(),
+        })
     }
 }
 
 impl<T> BucketizeSingle<T> for LinearBucketizer<T>
 where 
-    T: Sub<Output = T>
-    + Div<Output = T>
-    + PartialOrd
-    + IntoUsize
-    + Copy
+    T: Numeric + Default
 {
     /// Bucketizes a single value using the `LinearBucketizer`.
     ///
@@ -93,23 +572,158 @@ where
     ///
     /// assert_eq!(bucket, 1);
     /// ```
+    ///
+    /// Values at or below `start` are assigned to bucket 0 before any
+    /// conversion to `usize` takes place.
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    /// assert_eq!(bucketizer.bucketize(&-3.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&0.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&12.0), 2);
+    ///
+    /// let bucketizer = LinearBucketizer::new(10, 30, 4);
+    /// assert_eq!(bucketizer.bucketize(&-3), 0);
+    /// assert_eq!(bucketizer.bucketize(&7), 0);
+    /// assert_eq!(bucketizer.bucketize(&16), 1);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `NaN` and the policy is `NanPolicy::Error`.
+    /// Use `try_bucketize` to handle that case instead.
     fn bucketize(&self, value: &T) -> usize {
-        let bucket_index = ((*value - self.start) / self.bucket_width).into_usize();
-        if bucket_index < self.num_buckets {
-            bucket_index
-        } else {
-            self.num_buckets - 1
-        }
+        self.try_bucketize(value)
+            .expect("NaN value bucketized under NanPolicy::Error")
     }
 }
 
 impl<T, I> Bucketize<T, I> for LinearBucketizer<T>
 where
-    T: Sub<Output = T> 
-    + Div<Output = T> 
-    + PartialOrd
-    + IntoUsize
-    + Copy,
+    T: Numeric + Default,
     I: Iterator<Item = T>,
 {}
 
+impl<T> BucketizeRef<T> for LinearBucketizer<T>
+where
+    T: Numeric + Default,
+{
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T> BucketBounds<T> for LinearBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive
+{
+    /// Returns the `[low, high)` interval of the bucket at `index`.
+    ///
+    /// The bounds describe the nominal linear spacing; values above the 
+    /// last bucket's upper edge are still clamped into that bucket by 
+    /// `bucketize`. Returns `None` for `index >= num_buckets`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((0.0, 5.0)));
+    /// assert_eq!(bucketizer.bucket_bounds(3), Some((15.0, 20.0)));
+    /// assert_eq!(bucketizer.bucket_bounds(4), None);
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        if index >= self.num_buckets {
+            return None;
+        }
+
+        let low = self.start + self.bucket_width * T::from_usize(index)?;
+        let high = self.start + self.bucket_width * T::from_usize(index + 1)?;
+        Some((low, high))
+    }
+}
+
+impl<T> BucketCenter<T> for LinearBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive + Default
+{}
+
+impl<T> BucketCount for LinearBucketizer<T>
+where
+    T: Numeric,
+{
+    /// Returns the configured number of buckets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketCount;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// assert_eq!(bucketizer.num_buckets(), Some(4));
+    /// ```
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.num_buckets)
+    }
+}
+
+impl<T> fmt::Display for LinearBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive + fmt::Display
+{
+    /// Renders one line per bucket. The last bucket also receives every
+    /// value above `end`, so it is rendered as open ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0, 20, 4);
+    ///
+    /// assert_eq!(
+    ///     bucketizer.to_string(),
+    ///     "bucket 0: [0, 5)\nbucket 1: [5, 10)\nbucket 2: [10, 15)\nbucket 3: 15+",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_bucket_table(f, self, self.num_buckets, true)
+    }
+}
+
+impl<T> BucketDomain<T> for LinearBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive
+{
+    /// Returns `true` if `start <= value < end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketDomain;
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    ///
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// assert!(bucketizer.in_domain(&0.0));
+    /// assert!(bucketizer.in_domain(&19.9));
+    /// assert!(!bucketizer.in_domain(&-0.1));
+    /// assert!(!bucketizer.in_domain(&20.0));
+    /// assert!(!bucketizer.in_domain(&f64::NAN));
+    /// ```
+    fn in_domain(&self, value: &T) -> bool {
+        match self.num_buckets.checked_sub(1).and_then(|last| self.bucket_bounds(last)) {
+            Some((_, end)) => *value >= self.start && *value < end,
+            None => false,
+        }
+    }
+}
+
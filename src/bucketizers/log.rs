@@ -0,0 +1,191 @@
+use num_traits::Float;
+use crate::{bucketize::{BucketBounds, BucketCount, Bucketize, BucketizeRef, BucketizeSingle}, into_usize::IntoUsize};
+
+/// A bucketizer that uses logarithmically spaced buckets to bucketize data.
+///
+/// The `LogarithmicBucketizer` takes a start value, a logarithm base, and the
+/// number of buckets to create. Each bucket is `base` times wider than the
+/// previous one, which gives fine resolution near `start` while still covering
+/// several orders of magnitude.
+///
+/// ```
+/// use num_traits::Float;
+/// use buckets::into_usize::IntoUsize;
+///
+/// pub struct LogarithmicBucketizer<T>
+/// where
+///     T: Float + IntoUsize,
+/// {
+///     start: T,
+///     base: T,
+///     num_buckets: usize,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+{
+    start: T,
+    base: T,
+    num_buckets: usize,
+}
+
+impl<T> LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+{
+    /// Creates a new `LogarithmicBucketizer` with a specified start value, base, and number of buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The origin that values are measured from before taking the logarithm.
+    /// * `base` - The logarithm base, i.e. the growth factor between consecutive bucket boundaries.
+    /// * `num_buckets` - The number of buckets to create. Larger values are clamped into the last bucket.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::log::LogarithmicBucketizer;
+    ///
+    /// let start = 0.0;
+    /// let base = 10.0;
+    /// let num_buckets = 4;
+    ///
+    /// let bucketizer = LogarithmicBucketizer::new(start, base, num_buckets);
+    /// ```
+    pub fn new(start: T, base: T, num_buckets: usize) -> Self {
+        LogarithmicBucketizer {
+            start,
+            base,
+            num_buckets,
+        }
+    }
+}
+
+impl<T> BucketizeSingle<T> for LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+{
+    /// Bucketizes a single value using the `LogarithmicBucketizer`.
+    ///
+    /// The bucket index is `floor(log_base(value - start))`, clamped to the
+    /// range `0..num_buckets`. Values less than or equal to `start` land in
+    /// bucket 0.
+    ///
+    /// A value exactly on a power boundary (`value - start == base^k`) is
+    /// assigned to bucket `k`, i.e. boundaries are inclusive on the lower
+    /// edge, matching the other bucketizers in this crate. The index is
+    /// corrected after the floating point logarithm so that rounding error
+    /// does not push a boundary value into bucket `k - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::log::LogarithmicBucketizer;
+    ///
+    /// let bucketizer = LogarithmicBucketizer::new(0.0, 10.0, 4);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&1.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&15.0), 1);
+    /// assert_eq!(bucketizer.bucketize(&150.0), 2);
+    /// assert_eq!(bucketizer.bucketize(&1500.0), 3);
+    ///
+    /// // Power boundaries belong to the higher bucket
+    /// assert_eq!(bucketizer.bucketize(&100.0), 2);
+    /// assert_eq!(bucketizer.bucketize(&1000.0), 3);
+    ///
+    /// // Values at or below start land in the first bucket
+    /// assert_eq!(bucketizer.bucketize(&0.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&-5.0), 0);
+    ///
+    /// // Values beyond the last boundary are clamped into the last bucket
+    /// assert_eq!(bucketizer.bucketize(&1.0e9), 3);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        if *value <= self.start {
+            return 0;
+        }
+
+        let adjusted_value = (*value - self.start).max(T::epsilon());
+        let mut exponent = adjusted_value.log(self.base).floor();
+        if exponent < T::zero() {
+            return 0;
+        }
+
+        if self.base.powf(exponent + T::one()) <= adjusted_value {
+            exponent = exponent + T::one();
+        }
+
+        let bucket_index = exponent.into_usize();
+        if bucket_index < self.num_buckets {
+            bucket_index
+        } else {
+            self.num_buckets.saturating_sub(1)
+        }
+    }
+}
+
+impl<T, I> Bucketize<T, I> for LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+    I: Iterator<Item = T>,
+{}
+
+impl<T> BucketizeRef<T> for LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+{
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T> BucketBounds<T> for LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+{
+    /// Returns the `[low, high)` interval of the bucket at `index`.
+    ///
+    /// Bucket 0 spans `[start, start + base)`, and every following bucket 
+    /// `k` spans `[start + base^k, start + base^(k + 1))`. Returns `None` 
+    /// for `index >= num_buckets`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::log::LogarithmicBucketizer;
+    ///
+    /// let bucketizer = LogarithmicBucketizer::new(0.0, 10.0, 4);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((0.0, 10.0)));
+    /// assert_eq!(bucketizer.bucket_bounds(2), Some((100.0, 1000.0)));
+    /// assert_eq!(bucketizer.bucket_bounds(4), None);
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        if index >= self.num_buckets {
+            return None;
+        }
+
+        let exponent = T::from(index)?;
+        let low = if index == 0 {
+            self.start
+        } else {
+            self.start + self.base.powf(exponent)
+        };
+        let high = self.start + self.base.powf(exponent + T::one());
+        Some((low, high))
+    }
+}
+
+impl<T> BucketCount for LogarithmicBucketizer<T>
+where
+    T: Float + IntoUsize,
+{
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.num_buckets)
+    }
+}
@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeSingle};
+
+/// A bucketizer adapter that caches the bucket of every value it has
+/// seen, so repeated lookups of the same value skip the inner bucketizer.
+///
+/// The cache grows by one entry per distinct value and is never evicted,
+/// so this only helps for low-cardinality, integer-like inputs that are
+/// bucketized many times with an expensive inner bucketizer, such as a
+/// `RangeBucketizer` with many ranges. Float types do not implement
+/// `Hash + Eq` and cannot be memoized. The cache uses a `RefCell`, so a
+/// `MemoizedBucketizer` cannot be shared across threads.
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::collections::HashMap;
+///
+/// pub struct MemoizedBucketizer<T, B> {
+///     bucketizer: B,
+///     cache: RefCell<HashMap<T, usize>>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoizedBucketizer<T, B> {
+    bucketizer: B,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: RefCell<HashMap<T, usize>>,
+}
+
+impl<T, B> MemoizedBucketizer<T, B>
+where
+    T: Hash + Eq,
+{
+    /// Creates a new `MemoizedBucketizer` with an empty cache in front of
+    /// `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::memoized::MemoizedBucketizer;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = MemoizedBucketizer::<i32, _>::new(RangeBucketizer::new(vec![(0, 5), (5, 10)]));
+    ///
+    /// assert_eq!(bucketizer.cache_len(), 0);
+    /// ```
+    pub fn new(bucketizer: B) -> Self {
+        MemoizedBucketizer { bucketizer, cache: RefCell::new(HashMap::new()) }
+    }
+
+    /// Returns the number of distinct values in the cache.
+    pub fn cache_len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Empties the cache, e.g. to bound its memory use.
+    pub fn clear_cache(&self) {
+        self.cache.borrow_mut().clear();
+    }
+}
+
+impl<T, B> BucketizeSingle<T> for MemoizedBucketizer<T, B>
+where
+    T: PartialOrd + Copy + Hash + Eq,
+    B: BucketizeSingle<T>,
+{
+    /// Returns the cached bucket of `value`, bucketizing it with the inner
+    /// bucketizer and caching the result on first access.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::memoized::MemoizedBucketizer;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let ranges = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20), (20, i32::MAX)]);
+    /// let bucketizer = MemoizedBucketizer::new(ranges.clone());
+    ///
+    /// for value in [3, 7, 3, 12, 7, 25, 3] {
+    ///     assert_eq!(bucketizer.bucketize(&value), ranges.bucketize(&value));
+    /// }
+    ///
+    /// // Only the four distinct values were cached
+    /// assert_eq!(bucketizer.cache_len(), 4);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        if let Some(&bucket) = self.cache.borrow().get(value) {
+            return bucket;
+        }
+
+        let bucket = self.bucketizer.bucketize(value);
+        self.cache.borrow_mut().insert(*value, bucket);
+        bucket
+    }
+}
+
+impl<T, B, I> Bucketize<T, I> for MemoizedBucketizer<T, B>
+where
+    T: PartialOrd + Copy + Hash + Eq,
+    B: BucketizeSingle<T>,
+    I: Iterator<Item = T>,
+{}
+
+impl<T, B: BucketCount> BucketCount for MemoizedBucketizer<T, B> {
+    fn num_buckets(&self) -> Option<usize> {
+        self.bucketizer.num_buckets()
+    }
+}
@@ -0,0 +1,173 @@
+use core::ops::{Add, Div, Mul, Rem};
+use num_traits::{FromPrimitive, Zero};
+use crate::{bucketize::{BucketBounds, BucketCount, Bucketize, BucketizeSingle}, into_usize::IntoUsize};
+
+/// A bucketizer for cyclic data such as angles or hours of the day.
+///
+/// The `ModuloBucketizer` takes a period and the number of buckets, wraps
+/// each value into `[0, period)` and assigns it to one of `num_buckets`
+/// equal arcs. Negative values wrap around, so with a period of 360,
+/// `-45` is treated as `315`.
+///
+/// ```
+/// use std::ops::{Div, Rem};
+/// use num_traits::Zero;
+/// use buckets::into_usize::IntoUsize;
+///
+/// pub struct ModuloBucketizer<T>
+/// where
+///     T: PartialOrd + Rem<Output = T> + Div<Output = T> + Zero + Copy + IntoUsize,
+/// {
+///     period: T,
+///     num_buckets: usize,
+///     arc_width: T,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModuloBucketizer<T>
+where
+    T: PartialOrd + Rem<Output = T> + Div<Output = T> + Zero + Copy + IntoUsize,
+{
+    period: T,
+    num_buckets: usize,
+    arc_width: T,
+}
+
+impl<T> ModuloBucketizer<T>
+where
+    T: PartialOrd + Rem<Output = T> + Div<Output = T> + Zero + FromPrimitive + Copy + IntoUsize,
+{
+    /// Creates a new `ModuloBucketizer` with a specified period and number of buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The length of one cycle, e.g. 360 for degrees.
+    /// * `num_buckets` - The number of equal arcs each cycle is divided into.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is 0, if `num_buckets` cannot be represented
+    /// as a `T`, or if the arcs would not have a positive width, e.g. for
+    /// more integer arcs than the period is long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::modulo::ModuloBucketizer;
+    ///
+    /// let period = 360.0;
+    /// let num_buckets = 4;
+    ///
+    /// let bucketizer = ModuloBucketizer::new(period, num_buckets);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use buckets::bucketizers::modulo::ModuloBucketizer;
+    ///
+    /// // Four integer arcs do not fit in a period of 3
+    /// ModuloBucketizer::new(3, 4);
+    /// ```
+    pub fn new(period: T, num_buckets: usize) -> Self {
+        assert!(num_buckets > 0, "num_buckets must be at least 1");
+
+        let count = T::from_usize(num_buckets).expect("num_buckets must be representable");
+        let arc_width = period / count;
+        assert!(arc_width > T::zero(), "the period is too short for num_buckets arcs");
+
+        ModuloBucketizer {
+            period,
+            num_buckets,
+            arc_width,
+        }
+    }
+}
+
+impl<T> BucketizeSingle<T> for ModuloBucketizer<T>
+where
+    T: PartialOrd + Rem<Output = T> + Div<Output = T> + Zero + Copy + IntoUsize,
+{
+    /// Bucketizes a single value using the `ModuloBucketizer`.
+    ///
+    /// The value is wrapped into `[0, period)` before being divided by the
+    /// arc width, and the result is clamped to `num_buckets - 1` to guard
+    /// against floating point rounding at the end of the cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::modulo::ModuloBucketizer;
+    ///
+    /// let bucketizer = ModuloBucketizer::new(360, 4);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&45), 0);
+    /// assert_eq!(bucketizer.bucketize(&135), 1);
+    /// assert_eq!(bucketizer.bucketize(&-45), 3);
+    /// assert_eq!(bucketizer.bucketize(&405), 0);
+    /// assert_eq!(bucketizer.bucketize(&90), 1);
+    ///
+    /// let bucketizer = ModuloBucketizer::new(360.0, 4);
+    /// assert_eq!(bucketizer.bucketize_iter(vec![45.0, 135.0, -45.0, 719.9].into_iter()), vec![0, 1, 3, 3]);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        let mut wrapped = *value % self.period;
+        if wrapped < T::zero() {
+            wrapped = wrapped + self.period;
+        }
+
+        let bucket_index = (wrapped / self.arc_width).into_usize();
+        bucket_index.min(self.num_buckets.saturating_sub(1))
+    }
+}
+
+impl<T, I> Bucketize<T, I> for ModuloBucketizer<T>
+where
+    T: PartialOrd + Rem<Output = T> + Div<Output = T> + Zero + Copy + IntoUsize,
+    I: Iterator<Item = T>,
+{}
+
+impl<T> BucketBounds<T> for ModuloBucketizer<T>
+where
+    T: PartialOrd
+    + Add<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + Zero
+    + FromPrimitive
+    + Copy
+    + IntoUsize
+{
+    /// Returns the `[low, high)` arc of the bucket at `index` within one
+    /// cycle, or `None` for `index >= num_buckets`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::modulo::ModuloBucketizer;
+    ///
+    /// let bucketizer = ModuloBucketizer::new(360, 4);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(1), Some((90, 180)));
+    /// assert_eq!(bucketizer.bucket_bounds(4), None);
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        if index >= self.num_buckets {
+            return None;
+        }
+
+        let low = self.arc_width * T::from_usize(index)?;
+        Some((low, low + self.arc_width))
+    }
+}
+
+impl<T> BucketCount for ModuloBucketizer<T>
+where
+    T: PartialOrd + Rem<Output = T> + Div<Output = T> + Zero + Copy + IntoUsize,
+{
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.num_buckets)
+    }
+}
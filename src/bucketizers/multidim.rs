@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+use crate::bucketize::BucketizeSingle;
+
+/// A bucketizer that bins multi-dimensional points by bucketizing each
+/// coordinate independently with its own bucketizer.
+///
+/// ```
+/// pub struct MultiDimBucketizer<B> {
+///     bucketizers: Vec<B>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiDimBucketizer<B> {
+    bucketizers: Vec<B>,
+}
+
+impl<B> MultiDimBucketizer<B> {
+    /// Creates a new `MultiDimBucketizer` where `bucketizers[i]` bucketizes
+    /// the `i`-th coordinate of each point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::multidim::MultiDimBucketizer;
+    ///
+    /// let bucketizer = MultiDimBucketizer::new(vec![
+    ///     FixedWidthBucketizer::new(5, 0),
+    ///     FixedWidthBucketizer::new(10, 0),
+    /// ]);
+    ///
+    /// assert_eq!(bucketizer.dims(), 2);
+    /// ```
+    pub fn new(bucketizers: Vec<B>) -> Self {
+        MultiDimBucketizer { bucketizers }
+    }
+
+    /// Returns the number of dimensions.
+    pub fn dims(&self) -> usize {
+        self.bucketizers.len()
+    }
+
+    /// Bucketizes each coordinate of `point` with its dimension's
+    /// bucketizer, returning one bucket per dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` does not have exactly one coordinate per dimension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::multidim::MultiDimBucketizer;
+    ///
+    /// let bucketizer = MultiDimBucketizer::new(vec![
+    ///     FixedWidthBucketizer::new(5, 0),
+    ///     FixedWidthBucketizer::new(10, 0),
+    /// ]);
+    ///
+    /// assert_eq!(bucketizer.bucketize_dims(&[7, 12]), vec![1, 1]);
+    /// assert_eq!(bucketizer.bucketize_dims(&[3, 25]), vec![0, 2]);
+    /// ```
+    pub fn bucketize_dims<T>(&self, point: &[T]) -> Vec<usize>
+    where
+        T: PartialOrd + Copy,
+        B: BucketizeSingle<T>,
+    {
+        assert_eq!(
+            point.len(),
+            self.bucketizers.len(),
+            "point must have one coordinate per dimension"
+        );
+
+        self.bucketizers
+            .iter()
+            .zip(point)
+            .map(|(bucketizer, value)| bucketizer.bucketize(value))
+            .collect()
+    }
+
+    /// Bucketizes `point` into a single row-major index over a grid with
+    /// `cardinalities[i]` buckets along dimension `i`, so the last
+    /// dimension varies fastest.
+    ///
+    /// Returns `None` if `cardinalities` does not have one entry per
+    /// dimension or if a coordinate's bucket does not fit within its
+    /// dimension's cardinality.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` does not have exactly one coordinate per dimension.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::bucketizers::multidim::MultiDimBucketizer;
+    ///
+    /// let bucketizer = MultiDimBucketizer::new(vec![
+    ///     FixedWidthBucketizer::new(5, 0),
+    ///     FixedWidthBucketizer::new(10, 0),
+    /// ]);
+    ///
+    /// // [7, 12] lands in buckets [1, 1], i.e. 1 * 4 + 1 on a 4x4 grid
+    /// assert_eq!(bucketizer.bucketize_flat(&[7, 12], &[4, 4]), Some(5));
+    /// assert_eq!(bucketizer.bucketize_flat(&[7, 12], &[4, 1]), None);
+    /// ```
+    pub fn bucketize_flat<T>(&self, point: &[T], cardinalities: &[usize]) -> Option<usize>
+    where
+        T: PartialOrd + Copy,
+        B: BucketizeSingle<T>,
+    {
+        if cardinalities.len() != self.bucketizers.len() {
+            return None;
+        }
+
+        self.bucketize_dims(point)
+            .into_iter()
+            .zip(cardinalities)
+            .try_fold(0usize, |flat, (bucket, &cardinality)| {
+                if bucket >= cardinality {
+                    return None;
+                }
+                flat.checked_mul(cardinality)?.checked_add(bucket)
+            })
+    }
+}
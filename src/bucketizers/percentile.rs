@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeRef, BucketizeSingle};
+
+/// A bucketizer that bins values by their percentile rank within a
+/// reference dataset, i.e. by the empirical CDF of the reference data.
+///
+/// A value's rank is the number of reference values less than or equal
+/// to it, and `num_buckets` equal slices of the `0..=len` rank range
+/// become the buckets.
+///
+/// ```
+/// pub struct PercentileRankBucketizer<T: PartialOrd + Copy> {
+///     reference: Vec<T>,
+///     num_buckets: usize,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PercentileRankBucketizer<T: PartialOrd + Copy> {
+    reference: Vec<T>,
+    num_buckets: usize,
+}
+
+impl<T: PartialOrd + Copy> PercentileRankBucketizer<T> {
+    /// Creates a new `PercentileRankBucketizer` from a reference dataset and a number of buckets.
+    ///
+    /// The reference data is expected to be sorted; an unsorted slice is
+    /// copied and sorted with a stable partial-order sort.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The dataset whose empirical CDF defines the buckets.
+    /// * `num_buckets` - The number of percentile rank buckets to create.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::percentile::PercentileRankBucketizer;
+    ///
+    /// let reference: Vec<i32> = (0..100).collect();
+    /// let bucketizer = PercentileRankBucketizer::new(&reference, 10);
+    /// ```
+    pub fn new(reference: &[T], num_buckets: usize) -> Self {
+        let mut reference = reference.to_vec();
+        reference.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        PercentileRankBucketizer { reference, num_buckets }
+    }
+
+    /// Returns the number of reference values less than or equal to
+    /// `value`, found by binary search.
+    pub fn rank(&self, value: &T) -> usize {
+        self.reference.partition_point(|reference| reference <= value)
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketizeSingle<T> for PercentileRankBucketizer<T> {
+    /// Bucketizes a single value using the `PercentileRankBucketizer`.
+    ///
+    /// The value's rank is scaled into `0..num_buckets`. Values below the
+    /// reference range land in the first bucket and values above it in
+    /// the last bucket. An empty reference dataset assigns every value to
+    /// bucket 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::percentile::PercentileRankBucketizer;
+    ///
+    /// let reference: Vec<i32> = (0..100).collect();
+    /// let bucketizer = PercentileRankBucketizer::new(&reference, 10);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&50), 5);
+    /// assert_eq!(bucketizer.bucketize(&5), 0);
+    /// assert_eq!(bucketizer.bucketize(&95), 9);
+    ///
+    /// // Values outside the reference range are clamped
+    /// assert_eq!(bucketizer.bucketize(&-100), 0);
+    /// assert_eq!(bucketizer.bucketize(&1000), 9);
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        if self.reference.is_empty() {
+            return 0;
+        }
+
+        let bucket_index = self.rank(value) * self.num_buckets / self.reference.len();
+        bucket_index.min(self.num_buckets.saturating_sub(1))
+    }
+}
+
+impl<T, I> Bucketize<T, I> for PercentileRankBucketizer<T>
+where
+    T: PartialOrd + Copy,
+    I: Iterator<Item = T>,
+{}
+
+impl<T: PartialOrd + Copy> BucketizeRef<T> for PercentileRankBucketizer<T> {
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketCount for PercentileRankBucketizer<T> {
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.num_buckets)
+    }
+}
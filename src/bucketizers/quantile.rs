@@ -1,23 +1,39 @@
-use crate::bucketize::{Bucketize, BucketizeSingle};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use crate::bucketize::{is_nan, BucketCount, BucketDomain, Bucketize, BucketizeRef, BucketizeSingle, DataError, NanError, NanPolicy};
+use crate::bucketizers::range::RangeBucketizer;
 
 /// A bucketizer struct to bin data into quantiles 
 ///
+/// `quantiles` holds the ascending cut points between buckets and 
+/// `n_quantiles` is the number of buckets, so `n` buckets are normally 
+/// described by `n - 1` cut points. A value equal to a cut point belongs 
+/// to the bucket that starts at that cut point, matching the 
+/// inclusive-lower, exclusive-upper convention of the other bucketizers.
+///
 /// ```
+/// use buckets::bucketize::NanPolicy;
+///
 /// pub struct QuantileBucketizer<T: PartialOrd + Copy> {
 ///     quantiles: Vec<T>,
-///     n_quantiles: usize
+///     n_quantiles: usize,
+///     nan_policy: NanPolicy,
 /// }
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QuantileBucketizer<T> 
 where 
     T: PartialOrd + Copy 
 {
     quantiles: Vec<T>,
-    n_quantiles: usize
+    n_quantiles: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    nan_policy: NanPolicy,
 }
 
 impl<T: PartialOrd + Copy> QuantileBucketizer<T> {
-    /// Creates a new QuantileBucketizer with the given quantiles.
+    /// Creates a new QuantileBucketizer with the given quantile cut points 
+    /// and number of buckets.
     ///
     /// # Example
     ///
@@ -25,20 +41,274 @@ impl<T: PartialOrd + Copy> QuantileBucketizer<T> {
     /// use buckets::bucketizers::quantile::QuantileBucketizer;
     ///
     /// let quantiles = vec![25.0, 50.0, 75.0];
-    /// let bucketizer = QuantileBucketizer::new(quantiles, 3);
+    /// let bucketizer = QuantileBucketizer::new(quantiles, 4);
     /// ```
     pub fn new(quantiles: Vec<T>, n_quantiles: usize) -> Self {
-        QuantileBucketizer { quantiles, n_quantiles}
+        QuantileBucketizer { quantiles, n_quantiles, nan_policy: NanPolicy::default() }
     }
 
+    /// Creates a new QuantileBucketizer by computing `n_quantiles` evenly
+    /// spaced quantile buckets from raw data.
+    ///
+    /// `NaN` values are ignored, the remaining values are sorted into a
+    /// copy, and the `n_quantiles - 1` interior boundaries
+    /// are taken at ranks `i * len / n_quantiles` for `i` in `1..n_quantiles`.
+    ///
+    /// Duplicate values are not removed, so heavily tied data can produce
+    /// repeated boundaries. Because a value lands in the first bucket whose
+    /// boundary is greater than it, a repeated boundary yields an empty
+    /// bucket rather than changing the number of buckets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::EmptyData` if `data` has no values other than `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, DataError};
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let bucketizer = QuantileBucketizer::from_data(&data, 4).unwrap();
+    ///
+    /// let binned: Vec<usize> = data.iter().map(|v| bucketizer.bucketize(v)).collect();
+    /// assert_eq!(binned, vec![0, 0, 1, 1, 2, 2, 3, 3]);
+    ///
+    /// assert_eq!(QuantileBucketizer::<i32>::from_data(&[], 4).unwrap_err(), DataError::EmptyData);
+    /// assert_eq!(QuantileBucketizer::from_data(&[f64::NAN], 4).unwrap_err(), DataError::EmptyData);
+    /// ```
+    pub fn from_data(data: &[T], n_quantiles: usize) -> Result<Self, DataError> {
+        let quantiles = cut_points(data, n_quantiles)?;
+
+        Ok(QuantileBucketizer { quantiles, n_quantiles, nan_policy: NanPolicy::default() })
+    }
+
+    /// Creates a new QuantileBucketizer like `from_data`, but with each
+    /// value counting in proportion to its weight.
+    ///
+    /// The values are sorted, and the `i`th of the `n_quantiles - 1` cut
+    /// points is the first value at which the cumulative weight exceeds
+    /// `i / n_quantiles` of the total weight. With equal weights this
+    /// matches `from_data`. Values whose weight is not positive and finite
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::EmptyData` if `data` is empty or no value other
+    /// than `NaN` has a positive, finite weight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, DataError};
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let unweighted = QuantileBucketizer::from_data(&values, 2).unwrap();
+    ///
+    /// // The same values, but 1 and 2 count three times as much
+    /// let weighted: Vec<(i32, f64)> = values
+    ///     .iter()
+    ///     .map(|&value| (value, if value <= 2 { 3.0 } else { 1.0 }))
+    ///     .collect();
+    /// let weighted = QuantileBucketizer::from_weighted_data(&weighted, 2).unwrap();
+    ///
+    /// // The median moves down from 5 to 3, so 4 is now above it
+    /// assert_eq!(unweighted.bucketize(&4), 0);
+    /// assert_eq!(weighted.bucketize(&4), 1);
+    /// assert_eq!(weighted.bucketize(&2), 0);
+    ///
+    /// // Equal weights reproduce the unweighted cut points
+    /// let equal: Vec<(i32, f64)> = values.iter().map(|&value| (value, 0.5)).collect();
+    /// let equal = QuantileBucketizer::from_weighted_data(&equal, 4).unwrap();
+    /// let unweighted = QuantileBucketizer::from_data(&values, 4).unwrap();
+    /// assert_eq!(equal.bucketize_slice(&values), unweighted.bucketize_slice(&values));
+    ///
+    /// assert_eq!(QuantileBucketizer::<i32>::from_weighted_data(&[], 4).unwrap_err(), DataError::EmptyData);
+    /// assert_eq!(QuantileBucketizer::from_weighted_data(&[(1, 0.0)], 4).unwrap_err(), DataError::EmptyData);
+    ///
+    /// // NaN values are ignored, whatever their weight
+    /// let mixed = [(f64::NAN, 5.0), (1.0, 1.0), (2.0, 1.0), (3.0, 1.0)];
+    /// let mixed = QuantileBucketizer::from_weighted_data(&mixed, 2).unwrap();
+    /// assert_eq!(mixed.bucketize_slice(&[1.0, 3.0]), vec![0, 1]);
+    /// ```
+    pub fn from_weighted_data(data: &[(T, f64)], n_quantiles: usize) -> Result<Self, DataError> {
+        let mut sorted: Vec<(T, f64)> = data
+            .iter()
+            .copied()
+            .filter(|(value, weight)| !is_nan(value) && *weight > 0.0 && weight.is_finite())
+            .collect();
+        if sorted.is_empty() {
+            return Err(DataError::EmptyData);
+        }
+        sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let total: f64 = sorted.iter().map(|&(_, weight)| weight).sum();
+        let mut quantiles = Vec::new();
+        let mut cumulative = 0.0;
+        let mut values = sorted.iter();
+        let mut current = None;
+        for i in 1..n_quantiles {
+            let target = total * i as f64 / n_quantiles as f64;
+            while cumulative <= target {
+                match values.next() {
+                    Some(&(value, weight)) => {
+                        cumulative += weight;
+                        current = Some(value);
+                    }
+                    None => break,
+                }
+            }
+            quantiles.extend(current);
+        }
+
+        Ok(QuantileBucketizer { quantiles, n_quantiles, nan_policy: NanPolicy::default() })
+    }
+
+    /// Returns the number of buckets, which bounds the indices returned 
+    /// by `bucketize`.
     pub fn get_n_quantiles(&self) -> usize {
         self.n_quantiles
     }
+
+    /// Sets how `NaN` values are bucketized. Defaults to `NanPolicy::ToLast`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, NanPolicy};
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let bucketizer = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4);
+    /// assert_eq!(bucketizer.bucketize(&f64::NAN), 3);
+    ///
+    /// let bucketizer = bucketizer.with_nan_policy(NanPolicy::ToFirst);
+    /// assert_eq!(bucketizer.bucketize(&f64::NAN), 0);
+    /// ```
+    pub fn with_nan_policy(mut self, nan_policy: NanPolicy) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    /// Bucketizes a single value like `bucketize`, but returns `NanError`
+    /// instead of panicking for `NaN` under `NanPolicy::Error`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{NanError, NanPolicy};
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let bucketizer = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4)
+    ///     .with_nan_policy(NanPolicy::Error);
+    ///
+    /// assert_eq!(bucketizer.try_bucketize(&55.0), Ok(2));
+    /// assert_eq!(bucketizer.try_bucketize(&f64::NAN), Err(NanError));
+    /// ```
+    pub fn try_bucketize(&self, value: &T) -> Result<usize, NanError> {
+        if is_nan(value) {
+            return self.nan_policy.resolve(self.n_quantiles);
+        }
+
+        let bucket_index = self.quantiles
+            .iter()
+            .position(|&quantile| value < &quantile)
+            .unwrap_or(self.quantiles.len());
+        Ok(bucket_index.min(self.n_quantiles.saturating_sub(1)))
+    }
+
+    /// Bucketizes every element of `data`, which must be sorted in
+    /// ascending order, by advancing a cursor through the cut points
+    /// instead of scanning them for every value.
+    ///
+    /// Because the cut points are ascending, the bucket of each value is
+    /// at least the bucket of the one before it, so the whole slice is
+    /// bucketized in a single pass over the data and the cut points. `NaN`
+    /// values are bucketized with `bucketize` and leave the cursor alone.
+    ///
+    /// The output is unspecified if `data` is not sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` contains `NaN` and the policy is `NanPolicy::Error`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let bucketizer = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4);
+    /// let data = vec![-1.0, 10.0, 25.0, 30.0, 50.0, 74.9, 75.0, 100.0];
+    ///
+    /// assert_eq!(bucketizer.bucketize_sorted(&data), bucketizer.bucketize_slice(&data));
+    /// assert_eq!(bucketizer.bucketize_sorted(&data), vec![0, 0, 1, 1, 2, 2, 3, 3]);
+    /// ```
+    pub fn bucketize_sorted(&self, data: &[T]) -> Vec<usize> {
+        let last = self.n_quantiles.saturating_sub(1);
+        let mut cursor = 0;
+        data.iter()
+            .map(|value| {
+                if is_nan(value) {
+                    return self.bucketize(value);
+                }
+                while cursor < self.quantiles.len() && *value >= self.quantiles[cursor] {
+                    cursor += 1;
+                }
+                cursor.min(last)
+            })
+            .collect()
+    }
+
+    /// Materializes the cut points as contiguous ranges, `[low, c1)`, 
+    /// `[c1, c2)`, ..., `[cn, high]`, in an equivalent `RangeBucketizer`.
+    ///
+    /// `low` and `high` close the first and last bucket, and are expected 
+    /// to enclose every cut point. Values in `[low, high]` are assigned to 
+    /// the same bucket by both bucketizers, while values outside it fall 
+    /// back to the last range of the `RangeBucketizer`. Cut points beyond 
+    /// the `n_quantiles - 1` used by `bucketize` are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let quantiles = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4);
+    /// let ranges = quantiles.to_ranges(0.0, 100.0);
+    ///
+    /// assert_eq!(ranges.ranges(), &[(0.0, 25.0), (25.0, 50.0), (50.0, 75.0), (75.0, 100.0)]);
+    ///
+    /// let data = vec![0.0, 10.0, 25.0, 30.0, 50.0, 74.9, 75.0, 99.0, 100.0];
+    /// assert_eq!(ranges.bucketize_slice(&data), quantiles.bucketize_slice(&data));
+    /// ```
+    pub fn to_ranges(&self, low: T, high: T) -> RangeBucketizer<T> {
+        let cuts = &self.quantiles[..self.quantiles.len().min(self.n_quantiles.saturating_sub(1))];
+
+        let mut boundaries = Vec::with_capacity(cuts.len() + 2);
+        boundaries.push(low);
+        boundaries.extend_from_slice(cuts);
+        boundaries.push(high);
+
+        let ranges = boundaries
+            .windows(2)
+            .map(|window| (window[0], window[1]))
+            .collect();
+
+        RangeBucketizer::new(ranges).with_inclusive_end()
+    }
 }
 
 impl<T: PartialOrd + Copy> BucketizeSingle<T> for QuantileBucketizer<T> {
     /// Bucketizes a single value using the QuantileBucketizer.
     ///
+    /// The value is assigned to the first bucket whose upper cut point is 
+    /// greater than it, so a value exactly equal to a cut point lands in 
+    /// the higher bucket. The result is clamped to `n_quantiles - 1`, and a 
+    /// bucketizer without cut points assigns every value to bucket 0.
+    ///
     /// # Example
     ///
     /// ```
@@ -46,17 +316,33 @@ impl<T: PartialOrd + Copy> BucketizeSingle<T> for QuantileBucketizer<T> {
     /// use buckets::bucketizers::quantile::QuantileBucketizer;
     ///
     /// let quantiles = vec![25.0, 50.0, 75.0];
-    /// let bucketizer = QuantileBucketizer::new(quantiles, 3);
+    /// let bucketizer = QuantileBucketizer::new(quantiles, 4);
     ///
     /// let value: f64 = 55.0;
     /// let bucket = bucketizer.bucketize(&value);
     /// assert_eq!(bucket, 2);
+    ///
+    /// // Values on a cut point start the next bucket
+    /// assert_eq!(bucketizer.bucketize(&25.0), 1);
+    /// assert_eq!(bucketizer.bucketize(&50.0), 2);
+    /// assert_eq!(bucketizer.bucketize(&75.0), 3);
+    ///
+    /// // `n_quantiles` bounds the output
+    /// let bucketizer = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 3);
+    /// assert_eq!(bucketizer.bucketize(&90.0), 2);
+    ///
+    /// // No cut points means a single bucket
+    /// let bucketizer = QuantileBucketizer::<f64>::new(vec![], 1);
+    /// assert_eq!(bucketizer.bucketize(&90.0), 0);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `NaN` and the policy is `NanPolicy::Error`.
+    /// Use `try_bucketize` to handle that case instead.
     fn bucketize(&self, value: &T) -> usize {
-        self.quantiles
-            .iter()
-            .position(|&quantile| value < &quantile)
-            .unwrap_or(self.quantiles.len())
+        self.try_bucketize(value)
+            .expect("NaN value bucketized under NanPolicy::Error")
     }
 }
 
@@ -65,3 +351,209 @@ where
     T: PartialOrd + Copy,
     I: Iterator<Item = T>,
 {}
+
+impl<T: PartialOrd + Copy> BucketizeRef<T> for QuantileBucketizer<T> {
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketCount for QuantileBucketizer<T> {
+    /// Returns `n_quantiles`, the number of quantile buckets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketCount;
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let bucketizer = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4);
+    ///
+    /// assert_eq!(bucketizer.num_buckets(), Some(4));
+    /// ```
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.n_quantiles)
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketDomain<T> for QuantileBucketizer<T> {
+    /// Returns `true` if `value` lies between the first and the last cut
+    /// point, inclusively. Without cut points there is a single bucket,
+    /// and every comparable value is in the domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketDomain;
+    /// use buckets::bucketizers::quantile::QuantileBucketizer;
+    ///
+    /// let bucketizer = QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4);
+    ///
+    /// assert!(bucketizer.in_domain(&25.0));
+    /// assert!(bucketizer.in_domain(&60.0));
+    /// assert!(bucketizer.in_domain(&75.0));
+    /// assert!(!bucketizer.in_domain(&10.0));
+    /// assert!(!bucketizer.in_domain(&80.0));
+    /// ```
+    fn in_domain(&self, value: &T) -> bool {
+        match (self.quantiles.first(), self.quantiles.last()) {
+            (Some(first), Some(last)) => value >= first && value <= last,
+            _ => !is_nan(value),
+        }
+    }
+}
+
+
+/// Returns the `n_quantiles - 1` cut points of the values of `data` other
+/// than `NaN`, taken at ranks `i * len / n_quantiles` of a sorted copy, or
+/// `DataError::EmptyData` if there are no such values.
+fn cut_points<T: PartialOrd + Copy>(data: &[T], n_quantiles: usize) -> Result<Vec<T>, DataError> {
+    let mut sorted: Vec<T> = data.iter().copied().filter(|value| !is_nan(value)).collect();
+    if sorted.is_empty() {
+        return Err(DataError::EmptyData);
+    }
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    Ok((1..n_quantiles)
+        .map(|i| sorted[i * sorted.len() / n_quantiles])
+        .collect())
+}
+
+/// A fixed-size uniform random sample of a stream, from which quantile 
+/// cut points can be estimated without holding the whole stream in 
+/// memory, e.g. to configure a `QuantileBucketizer`.
+///
+/// The reservoir keeps the first `capacity` values, and afterwards 
+/// replaces a random kept value with the `n`th value with probability 
+/// `capacity / n`, so every value seen is equally likely to be sampled. 
+/// The random numbers come from a small deterministic generator, so the 
+/// same seed and stream always produce the same sample.
+///
+/// # Sampling error
+///
+/// Cut points are exact quantiles of the sample, not of the stream. For a 
+/// sample of `k` values, the fraction of the stream below the estimated 
+/// `p` quantile has a standard deviation of about `sqrt(p * (1 - p) / k)`, 
+/// e.g. about 1.6% for the median of a 1000 value sample, independent of 
+/// the length of the stream. Quadrupling the capacity halves the error.
+///
+/// ```
+/// pub struct ReservoirQuantiles<T> {
+///     reservoir: Vec<T>,
+///     capacity: usize,
+///     seen: usize,
+///     state: u64,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReservoirQuantiles<T> {
+    reservoir: Vec<T>,
+    capacity: usize,
+    seen: usize,
+    state: u64,
+}
+
+impl<T: PartialOrd + Copy> ReservoirQuantiles<T> {
+    /// Creates a new, empty reservoir that samples up to `capacity` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::quantile::ReservoirQuantiles;
+    ///
+    /// let reservoir = ReservoirQuantiles::<f64>::new(1000);
+    ///
+    /// assert_eq!(reservoir.seen(), 0);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "reservoir capacity must be at least 1");
+
+        ReservoirQuantiles {
+            reservoir: Vec::with_capacity(capacity),
+            capacity,
+            seen: 0,
+            state: 0x853c_49e6_748f_ea9b,
+        }
+    }
+
+    /// Sets the seed of the random number generator that picks which 
+    /// values are kept.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.state = seed;
+        self
+    }
+
+    /// Offers `value` to the reservoir, which keeps it with probability 
+    /// `capacity / seen`.
+    pub fn update(&mut self, value: T) {
+        self.seen += 1;
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(value);
+            return;
+        }
+
+        // Multiplying by the range and keeping the high bits maps the 
+        // random number onto 0..seen without the bias of a modulo
+        let slot = ((self.next_random() as u128 * self.seen as u128) >> 64) as usize;
+        if slot < self.capacity {
+            self.reservoir[slot] = value;
+        }
+    }
+
+    /// Returns the number of values offered to the reservoir so far.
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// Returns the values currently sampled, in no particular order.
+    pub fn sample(&self) -> &[T] {
+        &self.reservoir
+    }
+
+    /// Estimates the `n_quantiles - 1` cut points between `n_quantiles` 
+    /// equally populated buckets from the current sample, the same way 
+    /// `QuantileBucketizer::from_data` does from the full data. The 
+    /// reservoir can keep receiving values afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::EmptyData` if no value other than `NaN` has been
+    /// offered yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::quantile::{QuantileBucketizer, ReservoirQuantiles};
+    ///
+    /// let mut reservoir = ReservoirQuantiles::new(1000);
+    /// for value in 0..100_000 {
+    ///     reservoir.update(value as f64);
+    /// }
+    ///
+    /// // The true median is 50,000, and the sampling error of the 
+    /// // median's rank is about 1.6%, so 5% is over three deviations
+    /// let cuts = reservoir.finish(2).unwrap();
+    /// assert!((cuts[0] - 50_000.0).abs() < 5_000.0);
+    ///
+    /// let bucketizer = QuantileBucketizer::new(reservoir.finish(4).unwrap(), 4);
+    /// assert_eq!(bucketizer.bucketize(&10.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&99_990.0), 3);
+    /// ```
+    pub fn finish(&self, n_quantiles: usize) -> Result<Vec<T>, DataError> {
+        cut_points(&self.reservoir, n_quantiles)
+    }
+
+    /// Advances the splitmix64 generator and returns its next output.
+    fn next_random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
@@ -1,4 +1,51 @@
-use crate::bucketize::{Bucketize, BucketizeSingle};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Range, Sub};
+use num_traits::{Bounded, FromPrimitive};
+use crate::bucketize::{fmt_bucket_table, is_nan, BucketBounds, BucketCenter, BucketCount, BucketDomain, DataError, Bucketize, BucketizeRef, BucketizeSingle};
+use crate::numeric::Numeric;
+
+/// The reasons a list of ranges can be rejected by `RangeBucketizer::try_new`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    /// No ranges were provided.
+    Empty,
+    /// The range at `index` does not satisfy `start < end`.
+    InvertedBounds { index: usize },
+    /// The ranges at `first` and `second` overlap.
+    Overlapping { first: usize, second: usize },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::Empty => write!(f, "no ranges were provided"),
+            RangeError::InvertedBounds { index } => {
+                write!(f, "range {} has a start that is not less than its end", index)
+            }
+            RangeError::Overlapping { first, second } => {
+                write!(f, "ranges {} and {} overlap", first, second)
+            }
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+/// Which end of each range in a `RangeBucketizer` is inclusive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoundMode {
+    /// Ranges are `[start, end)`, so a value on a boundary starts the next
+    /// range.
+    #[default]
+    LowerInclusive,
+    /// Ranges are `(start, end]`, so a value on a boundary ends the
+    /// previous range.
+    UpperInclusive,
+}
 
 /// A bucketizer that uses predefined ranges to bucketize data.
 ///
@@ -10,13 +57,57 @@ use crate::bucketize::{Bucketize, BucketizeSingle};
 /// # Example
 ///
 /// ```
+/// use buckets::bucketizers::range::BoundMode;
+///
 /// pub struct RangeBucketizer<T: PartialOrd + Copy> {
 ///     ranges: Vec<(T, T)>,
+///     sorted: bool,
+///     underflow: bool,
+///     overflow: bool,
+///     inclusive_end: bool,
+///     bound_mode: BoundMode,
 /// }
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "RangeBucketizerFields<T>"))]
 pub struct RangeBucketizer<T: PartialOrd + Copy> {
     ranges: Vec<(T, T)>,
+    // Recomputed on deserialization, so a payload cannot claim unsorted
+    // ranges are sorted and send `try_bucketize` down the binary search
+    #[cfg_attr(feature = "serde", serde(skip))]
+    sorted: bool,
+    underflow: bool,
+    overflow: bool,
+    inclusive_end: bool,
+    bound_mode: BoundMode,
+}
+
+/// The serialized fields of a `RangeBucketizer`, from which `sorted` is
+/// recomputed.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RangeBucketizerFields<T> {
+    ranges: Vec<(T, T)>,
+    #[serde(default)]
+    underflow: bool,
+    #[serde(default)]
+    overflow: bool,
+    #[serde(default)]
+    inclusive_end: bool,
+    #[serde(default)]
+    bound_mode: BoundMode,
+}
+
+#[cfg(feature = "serde")]
+impl<T: PartialOrd + Copy> From<RangeBucketizerFields<T>> for RangeBucketizer<T> {
+    fn from(fields: RangeBucketizerFields<T>) -> Self {
+        let RangeBucketizerFields { ranges, underflow, overflow, inclusive_end, bound_mode } = fields;
+        let sorted = ranges.iter().all(|(start, end)| start < end)
+            && ranges.windows(2).all(|window| window[0].1 == window[1].0);
+
+        RangeBucketizer { ranges, sorted, underflow, overflow, inclusive_end, bound_mode }
+    }
 }
 
 impl<T> RangeBucketizer<T> 
@@ -46,7 +137,408 @@ where
     /// // The bucketizer can now be used to bucketize data using the Bucketize trait.
     /// ```
     pub fn new(ranges: Vec<(T, T)>) -> Self {
-        RangeBucketizer { ranges }
+        RangeBucketizer { ranges, sorted: false, underflow: false, overflow: false, inclusive_end: false, bound_mode: BoundMode::LowerInclusive }
+    }
+
+    /// Creates a new `RangeBucketizer` whose last range is open ended, so
+    /// any value greater than or equal to the end of the last range falls
+    /// into the last bucket without needing a sentinel such as `i32::MAX`.
+    ///
+    /// This affects `try_bucketize`, `range_for` and `in_domain`.
+    /// `bucketize` is unchanged, since it already falls back to the last
+    /// bucket for any value outside every range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketDomain, BucketizeSingle};
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let ranges = vec![(0.0, 5.0), (5.0, 10.0), (10.0, 20.0)];
+    /// let closed = RangeBucketizer::new(ranges.clone());
+    /// let open = RangeBucketizer::with_overflow(ranges);
+    ///
+    /// assert_eq!(closed.try_bucketize(&1e12), None);
+    /// assert_eq!(open.try_bucketize(&1e12), Some(2));
+    /// assert_eq!(closed.range_for(&20.0), None);
+    /// assert_eq!(open.range_for(&20.0), Some((10.0, 20.0)));
+    /// assert!(!closed.in_domain(&20.0));
+    /// assert!(open.in_domain(&20.0));
+    ///
+    /// assert_eq!(closed.bucketize(&1e12), open.bucketize(&1e12));
+    /// ```
+    pub fn with_overflow(ranges: Vec<(T, T)>) -> Self {
+        RangeBucketizer { ranges, sorted: false, underflow: false, overflow: true, inclusive_end: false, bound_mode: BoundMode::LowerInclusive }
+    }
+
+    /// Makes the end of the last range inclusive, so a value exactly equal
+    /// to it belongs to the last bucket.
+    ///
+    /// This affects `try_bucketize`, `range_for` and `in_domain`.
+    /// `bucketize` is unchanged, since it already falls back to the last
+    /// bucket for any value outside every range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketDomain, BucketizeSingle};
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let exclusive = RangeBucketizer::new(vec![(0, 50), (50, 100)]);
+    /// let inclusive = exclusive.clone().with_inclusive_end();
+    ///
+    /// assert_eq!(exclusive.try_bucketize(&100), None);
+    /// assert_eq!(inclusive.try_bucketize(&100), Some(1));
+    /// assert_eq!(inclusive.range_for(&100), Some((50, 100)));
+    /// assert!(!exclusive.in_domain(&100));
+    /// assert!(inclusive.in_domain(&100));
+    ///
+    /// // Only the end itself is added, not the values past it
+    /// assert_eq!(inclusive.try_bucketize(&101), None);
+    /// assert_eq!(exclusive.bucketize(&100), inclusive.bucketize(&100));
+    /// ```
+    pub fn with_inclusive_end(mut self) -> Self {
+        self.inclusive_end = true;
+        self
+    }
+
+    /// Sets which end of each range is inclusive. Defaults to
+    /// `BoundMode::LowerInclusive`.
+    ///
+    /// With `BoundMode::UpperInclusive` the ranges are `(start, end]`, so
+    /// `with_inclusive_end` has no further effect, and ranges made open
+    /// ended by `with_overflow` receive every value above their end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::{BoundMode, RangeBucketizer};
+    ///
+    /// let ranges = vec![(0, 5), (5, 10), (10, 20)];
+    ///
+    /// let lower = RangeBucketizer::new(ranges.clone());
+    /// let upper = RangeBucketizer::new(ranges).with_bound_mode(BoundMode::UpperInclusive);
+    ///
+    /// // A value on a boundary moves to the previous bucket
+    /// assert_eq!(lower.bucketize(&5), 1);
+    /// assert_eq!(upper.bucketize(&5), 0);
+    /// assert_eq!(lower.bucketize(&10), 2);
+    /// assert_eq!(upper.bucketize(&10), 1);
+    ///
+    /// // Values between boundaries are unaffected
+    /// assert_eq!(lower.bucketize(&7), upper.bucketize(&7));
+    ///
+    /// assert_eq!(lower.try_bucketize(&0), Some(0));
+    /// assert_eq!(upper.try_bucketize(&0), None);
+    /// assert_eq!(upper.try_bucketize(&20), Some(2));
+    /// ```
+    pub fn with_bound_mode(mut self, bound_mode: BoundMode) -> Self {
+        self.bound_mode = bound_mode;
+        self
+    }
+
+    /// Creates a new `RangeBucketizer`, validating that the ranges are
+    /// well formed.
+    ///
+    /// Unlike `new`, this rejects an empty list, any range where
+    /// `start >= end`, and any two ranges that overlap. The ranges do not
+    /// need to be sorted or contiguous.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::range::{RangeBucketizer, RangeError};
+    ///
+    /// assert!(RangeBucketizer::try_new(vec![(0, 5), (5, 10), (20, 30)]).is_ok());
+    ///
+    /// assert_eq!(
+    ///     RangeBucketizer::try_new(vec![(0, 5), (10, 5)]).unwrap_err(),
+    ///     RangeError::InvertedBounds { index: 1 },
+    /// );
+    ///
+    /// assert_eq!(
+    ///     RangeBucketizer::try_new(vec![(0, 10), (20, 30), (5, 15)]).unwrap_err(),
+    ///     RangeError::Overlapping { first: 0, second: 2 },
+    /// );
+    ///
+    /// assert_eq!(
+    ///     RangeBucketizer::<i32>::try_new(vec![]).unwrap_err(),
+    ///     RangeError::Empty,
+    /// );
+    /// ```
+    pub fn try_new(ranges: Vec<(T, T)>) -> Result<Self, RangeError> {
+        if ranges.is_empty() {
+            return Err(RangeError::Empty);
+        }
+
+        let inverted = ranges
+            .iter()
+            .position(|(start, end)| start.partial_cmp(end) != Some(Ordering::Less));
+        if let Some(index) = inverted {
+            return Err(RangeError::InvertedBounds { index });
+        }
+
+        let mut order: Vec<usize> = (0..ranges.len()).collect();
+        order.sort_by(|&a, &b| {
+            ranges[a].0.partial_cmp(&ranges[b].0).unwrap_or(Ordering::Equal)
+        });
+        for window in order.windows(2) {
+            let (previous, next) = (window[0], window[1]);
+            if ranges[previous].1 > ranges[next].0 {
+                return Err(RangeError::Overlapping {
+                    first: previous.min(next),
+                    second: previous.max(next),
+                });
+            }
+        }
+
+        Ok(RangeBucketizer::new(ranges))
+    }
+
+    /// Creates a new `RangeBucketizer` with `n_bins` contiguous ranges
+    /// that each hold roughly the same number of values from `data`.
+    ///
+    /// A copy of `data` is sorted, and the boundaries between bins are
+    /// taken at ranks `i * len / n_bins` for `i` in `1..n_bins`. The first
+    /// range starts at the smallest value and the last range ends at the
+    /// largest value, inclusively.
+    ///
+    /// Repeated values at a boundary all land in the same bin, so with
+    /// ties the bins may be uneven, and boundaries that coincide are
+    /// merged, producing fewer than `n_bins` bins.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::EmptyData` if `data` has no values other than
+    /// `NaN`, which are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_bins` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, DataError};
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let data: Vec<i32> = (1..=100).collect();
+    /// let bucketizer = RangeBucketizer::equal_frequency(&data, 10).unwrap();
+    ///
+    /// let counts = bucketizer.histogram(data.into_iter());
+    /// assert_eq!(counts, vec![10; 10]);
+    ///
+    /// // Ties at a boundary are kept together
+    /// let bucketizer = RangeBucketizer::equal_frequency(&[1, 2, 2, 2, 2, 3], 2).unwrap();
+    /// assert_eq!(bucketizer.histogram(vec![1, 2, 2, 2, 2, 3].into_iter()), vec![1, 5]);
+    ///
+    /// assert_eq!(RangeBucketizer::<i32>::equal_frequency(&[], 2).unwrap_err(), DataError::EmptyData);
+    /// assert_eq!(RangeBucketizer::equal_frequency(&[f64::NAN], 2).unwrap_err(), DataError::EmptyData);
+    /// ```
+    pub fn equal_frequency(data: &[T], n_bins: usize) -> Result<Self, DataError> {
+        assert!(n_bins > 0, "at least one bin is required");
+
+        let mut sorted: Vec<T> = data.iter().copied().filter(|value| !is_nan(value)).collect();
+        if sorted.is_empty() {
+            return Err(DataError::EmptyData);
+        }
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let min = sorted[0];
+        let max = sorted[sorted.len() - 1];
+        let mut boundaries = vec![min];
+        for i in 1..n_bins {
+            let boundary = sorted[i * sorted.len() / n_bins];
+            if boundary > boundaries[boundaries.len() - 1] && boundary < max {
+                boundaries.push(boundary);
+            }
+        }
+        boundaries.push(max);
+
+        let ranges = boundaries
+            .windows(2)
+            .map(|window| (window[0], window[1]))
+            .collect();
+
+        Ok(RangeBucketizer { ranges, sorted: true, underflow: false, overflow: false, inclusive_end: true, bound_mode: BoundMode::LowerInclusive })
+    }
+
+    /// Creates a new `RangeBucketizer` from ranges that are sorted in
+    /// ascending order and contiguous, i.e. each range ends exactly where
+    /// the next one starts.
+    ///
+    /// Sorted, contiguous ranges are bucketized with a binary search over
+    /// the range bounds in `O(log n)` instead of the linear scan used by
+    /// `new`. Assignments are identical to the ones made by `new`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range has `start >= end` or if a range does not
+    /// start where the previous one ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let ranges: Vec<(i32, i32)> = (0..100).map(|i| (i * 10, (i + 1) * 10)).collect();
+    ///
+    /// let linear = RangeBucketizer::new(ranges.clone());
+    /// let sorted = RangeBucketizer::new_sorted(ranges);
+    ///
+    /// for value in -20..1020 {
+    ///     assert_eq!(linear.bucketize(&value), sorted.bucketize(&value));
+    /// }
+    /// assert_eq!(sorted.bucketize(&455), 45);
+    /// ```
+    pub fn new_sorted(ranges: Vec<(T, T)>) -> Self {
+        for (start, end) in ranges.iter() {
+            assert!(start < end, "range start must be less than its end");
+        }
+        for window in ranges.windows(2) {
+            assert!(
+                window[0].1 == window[1].0,
+                "ranges must be sorted and contiguous"
+            );
+        }
+
+        RangeBucketizer { ranges, sorted: true, underflow: false, overflow: false, inclusive_end: false, bound_mode: BoundMode::LowerInclusive }
+    }
+
+    /// Returns `true` if every range ends exactly where the next one
+    /// starts, in the order the ranges were given, i.e. if the ranges
+    /// partition their span without gaps. No ranges, or a single range,
+    /// are trivially contiguous.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let contiguous = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20)]);
+    /// assert!(contiguous.is_contiguous());
+    ///
+    /// let gapped = RangeBucketizer::new(vec![(0, 5), (5, 10), (20, 30)]);
+    /// assert!(!gapped.is_contiguous());
+    ///
+    /// // Out of order ranges do not form a partition in the given order
+    /// let unordered = RangeBucketizer::new(vec![(5, 10), (0, 5)]);
+    /// assert!(!unordered.is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        self.ranges.windows(2).all(|window| window[0].1 == window[1].0)
+    }
+}
+
+impl<T: PartialOrd + Copy + Bounded> RangeBucketizer<T> {
+    /// Creates a new `RangeBucketizer` from ascending breakpoints, so that
+    /// `n` breakpoints produce `n + 1` contiguous buckets. The first range
+    /// starts at `T::min_value()` and the last one ends at
+    /// `T::max_value()`, but both are open ended, so the first bucket
+    /// receives every value below the first breakpoint and the last bucket
+    /// every value from the last breakpoint up, including infinities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the breakpoints are not strictly ascending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::from_breakpoints(vec![5, 10, 20]);
+    ///
+    /// assert_eq!(bucketizer.bucketize(&i32::MIN), 0);
+    /// assert_eq!(bucketizer.bucketize(&4), 0);
+    /// assert_eq!(bucketizer.bucketize(&5), 1);
+    /// assert_eq!(bucketizer.bucketize(&9), 1);
+    /// assert_eq!(bucketizer.bucketize(&10), 2);
+    /// assert_eq!(bucketizer.bucketize(&19), 2);
+    /// assert_eq!(bucketizer.bucketize(&20), 3);
+    /// assert_eq!(bucketizer.bucketize(&i32::MAX), 3);
+    ///
+    /// let bucketizer = RangeBucketizer::from_breakpoints(vec![0.5]);
+    /// assert_eq!(bucketizer.bucketize(&-1e300), 0);
+    /// assert_eq!(bucketizer.bucketize(&f64::NEG_INFINITY), 0);
+    /// assert_eq!(bucketizer.bucketize(&0.5), 1);
+    /// assert_eq!(bucketizer.bucketize(&f64::INFINITY), 1);
+    /// assert_eq!(bucketizer.try_bucketize(&f64::NEG_INFINITY), Some(0));
+    /// ```
+    pub fn from_breakpoints(breaks: Vec<T>) -> Self {
+        for window in breaks.windows(2) {
+            assert!(window[0] < window[1], "breakpoints must be strictly ascending");
+        }
+
+        let mut edges = Vec::with_capacity(breaks.len() + 2);
+        edges.push(T::min_value());
+        edges.extend(breaks);
+        edges.push(T::max_value());
+
+        let ranges = edges.windows(2).map(|window| (window[0], window[1])).collect();
+
+        RangeBucketizer { ranges, sorted: true, underflow: true, overflow: true, inclusive_end: false, bound_mode: BoundMode::LowerInclusive }
+    }
+}
+
+impl<T> RangeBucketizer<T>
+where
+    T: Numeric + Add<Output = T> + Mul<Output = T> + FromPrimitive + Bounded + Default,
+{
+    /// Creates a new `RangeBucketizer` with `n` contiguous ranges of equal
+    /// width from `start` to `end`, followed by a range from `end` to
+    /// `T::max_value()` if `overflow` is set.
+    ///
+    /// The last uniform range always ends exactly at `end`, so for integer
+    /// types whose span is not a multiple of `n` it absorbs the remainder.
+    /// With `overflow`, values at or above `T::max_value()` also land in
+    /// the overflow range. Without it, values outside `[start, end)` fall
+    /// back to the last range like they do for `new`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, if `start >= end`, if `n` cannot be represented
+    /// as a `T`, or if the ranges would have zero width, e.g. for more
+    /// integer ranges than there are integers from `start` to `end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::uniform(0, 10, 5, false);
+    ///
+    /// assert_eq!(bucketizer.ranges(), &[(0, 2), (2, 4), (4, 6), (6, 8), (8, 10)]);
+    /// assert_eq!(bucketizer.bucketize(&3), 1);
+    ///
+    /// let bucketizer = RangeBucketizer::uniform(0.0, 1.0, 4, true);
+    ///
+    /// assert_eq!(bucketizer.ranges().len(), 5);
+    /// assert_eq!(bucketizer.ranges()[4], (1.0, f64::MAX));
+    /// assert_eq!(bucketizer.bucketize(&0.6), 2);
+    /// assert_eq!(bucketizer.bucketize(&1e9), 4);
+    /// ```
+    pub fn uniform(start: T, end: T, n: usize, overflow: bool) -> Self {
+        assert!(n > 0, "at least one range is required");
+        assert!(start < end, "start must be less than end");
+
+        let width = (end - start) / T::from_usize(n).expect("n must be representable");
+        assert!(width > T::default(), "the span from start to end is too narrow for n ranges");
+        let mut edges: Vec<T> = (0..n)
+            .map(|i| start + width * T::from_usize(i).expect("n must be representable"))
+            .collect();
+        edges.push(end);
+        if overflow && end < T::max_value() {
+            edges.push(T::max_value());
+        }
+
+        let ranges = edges.windows(2).map(|window| (window[0], window[1])).collect();
+
+        RangeBucketizer { ranges, sorted: true, underflow: false, overflow, inclusive_end: false, bound_mode: BoundMode::LowerInclusive }
     }
 }
 
@@ -73,15 +565,161 @@ impl<T: PartialOrd + Copy> BucketizeSingle<T> for RangeBucketizer<T> {
     ///
     /// assert_eq!(bucket, 1);
     /// ```
+    ///
+    /// Values outside every range fall back to the last bucket. Use
+    /// `try_bucketize` to detect them instead.
     fn bucketize(&self, value: &T) -> usize {
-        let bucket_position = self.ranges
-            .iter()
-            .position(|(start, end)| value >= start && value < end);
+        self.try_bucketize(value).unwrap_or(self.ranges.len() - 1)
+    }
+}
+
+impl<T: PartialOrd + Copy> RangeBucketizer<T> {
+    /// Returns the index of the range containing `value`, or `None` if no
+    /// range contains it, instead of falling back to the last bucket like
+    /// `bucketize`.
+    ///
+    /// Ranges made open ended by `with_overflow` or `from_breakpoints`, or
+    /// inclusive by `with_inclusive_end`, are taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (20, 30)]);
+    ///
+    /// assert_eq!(bucketizer.try_bucketize(&7), Some(1));
+    /// assert_eq!(bucketizer.try_bucketize(&20), Some(2));
+    /// assert_eq!(bucketizer.try_bucketize(&-1), None);
+    /// assert_eq!(bucketizer.try_bucketize(&15), None);
+    /// assert_eq!(bucketizer.try_bucketize(&30), None);
+    ///
+    /// let bucketizer = RangeBucketizer::with_overflow(vec![(0, 5), (5, 10)]);
+    /// assert_eq!(bucketizer.try_bucketize(&100), Some(1));
+    /// ```
+    pub fn try_bucketize(&self, value: &T) -> Option<usize> {
+        let upper_inclusive = self.bound_mode == BoundMode::UpperInclusive;
 
-        if let Some(val) = bucket_position {
-            return val
+        let bucket_position = if self.sorted {
+            let index = self.ranges.partition_point(|range| self.ends_before(range, value));
+            self.ranges
+                .get(index)
+                .filter(|range| self.contains(range, value))
+                .map(|_| index)
         } else {
-            return self.ranges.len() - 1 
+            self.ranges.iter().position(|range| self.contains(range, value))
+        };
+
+        bucket_position.or_else(|| {
+            let (first_start, _) = self.ranges.first()?;
+            let before_start = if upper_inclusive {
+                value <= first_start
+            } else {
+                value < first_start
+            };
+            if self.underflow && before_start {
+                return Some(0);
+            }
+
+            let (_, last_end) = self.ranges.last()?;
+            let past_end = if upper_inclusive {
+                self.overflow && value > last_end
+            } else {
+                (self.overflow && value >= last_end)
+                    || (self.inclusive_end && value == last_end)
+            };
+            past_end.then(|| self.ranges.len() - 1)
+        })
+    }
+
+    /// Returns the configured `(start, end)` ranges, where the range at 
+    /// index `i` is bucket `i`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (20, 30)]);
+    ///
+    /// assert_eq!(bucketizer.ranges(), &[(0, 5), (5, 10), (20, 30)]);
+    /// ```
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    /// Returns the range containing `value`, or `None` if no range 
+    /// contains it, following the same rules as `try_bucketize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (20, 30)]);
+    ///
+    /// assert_eq!(bucketizer.range_for(&7), Some((5, 10)));
+    /// assert_eq!(bucketizer.range_for(&20), Some((20, 30)));
+    /// assert_eq!(bucketizer.range_for(&15), None);
+    /// assert_eq!(bucketizer.range_for(&30), None);
+    ///
+    /// let bucketizer = RangeBucketizer::with_overflow(vec![(0, 5), (5, 10)]);
+    /// assert_eq!(bucketizer.range_for(&100), Some((5, 10)));
+    /// ```
+    pub fn range_for(&self, value: &T) -> Option<(T, T)> {
+        self.try_bucketize(value).map(|index| self.ranges[index])
+    }
+
+    /// Bucketizes every element of `data`, which must be sorted in
+    /// ascending order, by advancing a cursor through the ranges instead
+    /// of searching them for every value.
+    ///
+    /// Ranges that end at or before a value cannot contain any later
+    /// value, so the cursor never moves backwards. A value the range at
+    /// the cursor does not contain falls back to `bucketize`, so the result
+    /// always matches `bucketize_slice`, and the fast path applies whenever
+    /// the ranges are sorted as well.
+    ///
+    /// The output is unspecified if `data` is not sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (20, 30)]);
+    /// let data = vec![-3, 0, 2, 5, 9, 12, 20, 25, 30, 40];
+    ///
+    /// assert_eq!(bucketizer.bucketize_sorted(&data), bucketizer.bucketize_slice(&data));
+    /// assert_eq!(bucketizer.bucketize_sorted(&data), vec![2, 0, 0, 1, 1, 2, 2, 2, 2, 2]);
+    /// ```
+    pub fn bucketize_sorted(&self, data: &[T]) -> Vec<usize> {
+        let mut cursor = 0;
+        data.iter()
+            .map(|value| {
+                while cursor < self.ranges.len() && self.ends_before(&self.ranges[cursor], value) {
+                    cursor += 1;
+                }
+                match self.ranges.get(cursor) {
+                    Some(range) if self.contains(range, value) => cursor,
+                    _ => self.bucketize(value),
+                }
+            })
+            .collect()
+    }
+
+    fn contains(&self, (start, end): &(T, T), value: &T) -> bool {
+        match self.bound_mode {
+            BoundMode::LowerInclusive => value >= start && value < end,
+            BoundMode::UpperInclusive => value > start && value <= end,
+        }
+    }
+
+    fn ends_before(&self, (_, end): &(T, T), value: &T) -> bool {
+        match self.bound_mode {
+            BoundMode::LowerInclusive => end <= value,
+            BoundMode::UpperInclusive => end < value,
         }
     }
 }
@@ -91,3 +729,158 @@ where
     T: PartialOrd + Copy,
     I: Iterator<Item = T>,
 {}
+
+impl<T: PartialOrd + Copy> BucketBounds<T> for RangeBucketizer<T> {
+    /// Returns the configured `(start, end)` range of the bucket at `index`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20)]);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(1), Some((5, 10)));
+    /// assert_eq!(bucketizer.bucket_bounds(3), None);
+    /// ```
+    fn bucket_bounds(&self, index: usize) -> Option<(T, T)> {
+        self.ranges.get(index).copied()
+    }
+}
+
+impl<T> BucketCenter<T> for RangeBucketizer<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Div<Output = T> + FromPrimitive
+{}
+
+impl<T: PartialOrd + Copy> From<Range<T>> for RangeBucketizer<T> {
+    /// Creates a single-bucket `RangeBucketizer` from a `start..end` range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketBounds;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::from(0..5);
+    ///
+    /// assert_eq!(bucketizer.bucket_bounds(0), Some((0, 5)));
+    /// ```
+    fn from(range: Range<T>) -> Self {
+        RangeBucketizer::new(vec![(range.start, range.end)])
+    }
+}
+
+impl<T: PartialOrd + Copy> From<Vec<Range<T>>> for RangeBucketizer<T> {
+    /// Creates a `RangeBucketizer` from `start..end` ranges, which makes the
+    /// inclusive lower and exclusive upper bound of each bucket explicit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let from_ranges = RangeBucketizer::from(vec![0..5, 5..10, 10..20]);
+    /// let from_tuples = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20)]);
+    ///
+    /// for value in -5..25 {
+    ///     assert_eq!(from_ranges.bucketize(&value), from_tuples.bucketize(&value));
+    /// }
+    /// ```
+    fn from(ranges: Vec<Range<T>>) -> Self {
+        RangeBucketizer::new(
+            ranges
+                .into_iter()
+                .map(|range| (range.start, range.end))
+                .collect()
+        )
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketizeRef<T> for RangeBucketizer<T> {
+    fn bucketize_ref(&self, item: &T) -> usize {
+        self.bucketize(item)
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketCount for RangeBucketizer<T> {
+    /// Returns the number of configured ranges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketCount;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20)]);
+    ///
+    /// assert_eq!(bucketizer.num_buckets(), Some(3));
+    /// ```
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.ranges.len())
+    }
+}
+
+impl<T: PartialOrd + Copy + fmt::Display> fmt::Display for RangeBucketizer<T> {
+    /// Renders one line per range. The last range is rendered as open
+    /// ended if the bucketizer was created with `with_overflow`, and ranges
+    /// are rendered as `(start, end]` under `BoundMode::UpperInclusive`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::range::{BoundMode, RangeBucketizer};
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (10, 20)]);
+    /// assert_eq!(bucketizer.to_string(), "bucket 0: [0, 5)\nbucket 1: [5, 10)\nbucket 2: [10, 20)");
+    ///
+    /// let bucketizer = RangeBucketizer::with_overflow(vec![(0, 5), (5, 10), (10, 20)]);
+    /// assert_eq!(bucketizer.to_string(), "bucket 0: [0, 5)\nbucket 1: [5, 10)\nbucket 2: 10+");
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10)]).with_bound_mode(BoundMode::UpperInclusive);
+    /// assert_eq!(bucketizer.to_string(), "bucket 0: (0, 5]\nbucket 1: (5, 10]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bound_mode == BoundMode::LowerInclusive {
+            return fmt_bucket_table(f, self, self.ranges.len(), self.overflow);
+        }
+
+        let last = self.ranges.len().saturating_sub(1);
+        for (index, (start, end)) in self.ranges.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            if self.overflow && index == last {
+                write!(f, "bucket {}: {}+", index, start)?;
+            } else {
+                write!(f, "bucket {}: ({}, {}]", index, start, end)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: PartialOrd + Copy> BucketDomain<T> for RangeBucketizer<T> {
+    /// Returns `true` if any range contains `value`, i.e. if
+    /// `try_bucketize` finds a bucket for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketDomain;
+    /// use buckets::bucketizers::range::RangeBucketizer;
+    ///
+    /// let bucketizer = RangeBucketizer::new(vec![(0, 5), (5, 10), (20, 30)]);
+    ///
+    /// assert!(bucketizer.in_domain(&7));
+    /// assert!(bucketizer.in_domain(&20));
+    /// assert!(!bucketizer.in_domain(&15));
+    /// assert!(!bucketizer.in_domain(&30));
+    /// ```
+    fn in_domain(&self, value: &T) -> bool {
+        self.try_bucketize(value).is_some()
+    }
+}
+
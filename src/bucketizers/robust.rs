@@ -0,0 +1,179 @@
+use core::cmp::Ordering;
+use num_traits::Float;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeSingle, DataError};
+
+/// A bucketizer that bins values by their robust score, i.e. by how many
+/// interquartile ranges they lie from the median.
+///
+/// Unlike the mean and standard deviation used by `GaussianBucketizer`,
+/// the median and interquartile range are barely affected by outliers, so
+/// a few extreme values do not stretch the central buckets. With bands
+/// `[-1, 0, 1]` there are four buckets: below -1 IQR, [-1, 0), [0, 1) and
+/// 1 IQR and above.
+///
+/// ```
+/// use num_traits::Float;
+///
+/// pub struct RobustBucketizer<T: Float> {
+///     median: T,
+///     iqr: T,
+///     bands: Vec<T>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RobustBucketizer<T: Float> {
+    median: T,
+    iqr: T,
+    bands: Vec<T>,
+}
+
+impl<T: Float> RobustBucketizer<T> {
+    /// Creates a new `RobustBucketizer` from the median and interquartile
+    /// range of `data`. Quartiles are linearly interpolated between ranks,
+    /// and `NaN` values are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values the median and interquartile range are computed from.
+    /// * `bands` - The ascending robust scores at which a new bucket begins.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::EmptyData` if `data` has no values other than `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketizeSingle, DataError};
+    /// use buckets::bucketizers::robust::RobustBucketizer;
+    ///
+    /// let data = [-1000.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1000.0];
+    /// let bucketizer = RobustBucketizer::from_data(&data, vec![-1.0, -0.5, 0.0, 0.5, 1.0]).unwrap();
+    ///
+    /// // The outliers do not move the median or widen the interquartile range
+    /// assert_eq!(bucketizer.median(), 5.0);
+    /// assert_eq!(bucketizer.iqr(), 5.0);
+    ///
+    /// // So the central values still spread over the central buckets
+    /// let central = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    /// assert_eq!(bucketizer.bucketize_slice(&central), vec![1, 1, 2, 2, 3, 3, 3, 4, 4]);
+    ///
+    /// // While the outliers land in the outermost buckets
+    /// assert_eq!(bucketizer.bucketize(&-1000.0), 0);
+    /// assert_eq!(bucketizer.bucketize(&1000.0), 5);
+    ///
+    /// assert_eq!(RobustBucketizer::<f64>::from_data(&[], vec![0.0]).unwrap_err(), DataError::EmptyData);
+    /// ```
+    pub fn from_data(data: &[T], bands: Vec<T>) -> Result<Self, DataError> {
+        let mut sorted: Vec<T> = data.iter().copied().filter(|value| !value.is_nan()).collect();
+        if sorted.is_empty() {
+            return Err(DataError::EmptyData);
+        }
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let median = quantile(&sorted, 0.5);
+        let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+
+        Ok(RobustBucketizer { median, iqr, bands })
+    }
+
+    /// Returns the median of the data.
+    pub fn median(&self) -> T {
+        self.median
+    }
+
+    /// Returns the interquartile range of the data.
+    pub fn iqr(&self) -> T {
+        self.iqr
+    }
+
+    /// Returns the robust score of `value`, `(value - median) / iqr`.
+    ///
+    /// If the interquartile range is zero, e.g. because most values are
+    /// equal, the score is zero for the median and infinite with the sign
+    /// of `value - median` for every other value, instead of dividing by
+    /// zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::robust::RobustBucketizer;
+    ///
+    /// let bucketizer = RobustBucketizer::from_data(&[3.0, 3.0, 3.0, 3.0, 9.0], vec![-1.0, 0.0, 1.0]).unwrap();
+    /// assert_eq!(bucketizer.iqr(), 0.0);
+    ///
+    /// assert_eq!(bucketizer.score(&3.0), 0.0);
+    /// assert_eq!(bucketizer.score(&9.0), f64::INFINITY);
+    /// assert_eq!(bucketizer.score(&1.0), f64::NEG_INFINITY);
+    /// assert_eq!(bucketizer.bucketize_slice(&[1.0, 3.0, 9.0]), vec![0, 2, 3]);
+    /// ```
+    pub fn score(&self, value: &T) -> T {
+        let offset = *value - self.median;
+        if self.iqr == T::zero() {
+            return match offset.partial_cmp(&T::zero()) {
+                Some(Ordering::Greater) => T::infinity(),
+                Some(Ordering::Less) => T::neg_infinity(),
+                _ => offset,
+            };
+        }
+        offset / self.iqr
+    }
+}
+
+/// Returns the linearly interpolated `q` quantile of ascending `sorted`
+/// values, or zero if there are none.
+fn quantile<T: Float>(sorted: &[T], q: f64) -> T {
+    if sorted.is_empty() {
+        return T::zero();
+    }
+
+    let position = (sorted.len() - 1) as f64 * q;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = T::from(position - lower as f64).unwrap_or_else(T::zero);
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
+
+impl<T: Float> BucketizeSingle<T> for RobustBucketizer<T> {
+    /// Bucketizes a single value using the `RobustBucketizer`.
+    ///
+    /// The value is assigned to the first bucket whose upper band is
+    /// greater than its robust score, so a value exactly on a band starts
+    /// the next bucket.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::BucketizeSingle;
+    /// use buckets::bucketizers::robust::RobustBucketizer;
+    ///
+    /// let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let bucketizer = RobustBucketizer::from_data(&data, vec![-1.0, 0.0, 1.0]).unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucketize(&3.0), 2); // at the median
+    /// assert_eq!(bucketizer.bucketize(&2.5), 1); // -0.25 IQR
+    /// assert_eq!(bucketizer.bucketize(&5.0), 3); // +1 IQR
+    /// ```
+    fn bucketize(&self, value: &T) -> usize {
+        let score = self.score(value);
+        self.bands
+            .iter()
+            .position(|band| score < *band)
+            .unwrap_or(self.bands.len())
+    }
+}
+
+impl<T, I> Bucketize<T, I> for RobustBucketizer<T>
+where
+    T: Float,
+    I: Iterator<Item = T>,
+{}
+
+impl<T: Float> BucketCount for RobustBucketizer<T> {
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.bands.len() + 1)
+    }
+}
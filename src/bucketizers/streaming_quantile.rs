@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+use crate::bucketize::{BucketCount, Bucketize, BucketizeSingle};
+
+/// A single P² estimator tracking one quantile with five markers.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct P2Estimator {
+    p: f64,
+    count: usize,
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Estimator {
+    fn new(p: f64) -> Self {
+        P2Estimator {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    fn update(&mut self, value: f64) {
+        // The first five observations initialize the markers directly
+        if self.count < 5 {
+            self.heights[self.count] = value;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            }
+            return;
+        }
+        self.count += 1;
+
+        let cell = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[4] {
+            self.heights[4] = value;
+            3
+        } else {
+            (0..4)
+                .find(|&i| value < self.heights[i + 1])
+                .unwrap_or(3)
+        };
+
+        for position in self.positions.iter_mut().skip(cell + 1) {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        for i in 1..4 {
+            let offset = self.desired[i] - self.positions[i];
+            let room_above = self.positions[i + 1] - self.positions[i];
+            let room_below = self.positions[i - 1] - self.positions[i];
+
+            if (offset >= 1.0 && room_above > 1.0) || (offset <= -1.0 && room_below < -1.0) {
+                let step = offset.signum();
+                let parabolic = self.parabolic(i, step);
+                self.heights[i] = if self.heights[i - 1] < parabolic && parabolic < self.heights[i + 1] {
+                    parabolic
+                } else {
+                    self.linear(i, step)
+                };
+                self.positions[i] += step;
+            }
+        }
+    }
+
+    fn parabolic(&self, i: usize, step: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + step / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + step) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - step) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    fn linear(&self, i: usize, step: f64) -> f64 {
+        let j = if step > 0.0 { i + 1 } else { i - 1 };
+        self.heights[i] + step * (self.heights[j] - self.heights[i]) / (self.positions[j] - self.positions[i])
+    }
+
+    fn estimate(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            count if count < 5 => {
+                let mut seen = self.heights;
+                seen[..count].sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                let index = ((count - 1) as f64 * self.p).round() as usize;
+                Some(seen[index])
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+}
+
+/// A bucketizer that estimates quantiles of an unbounded stream online
+/// with the P² algorithm, and bucketizes values against the current
+/// estimates.
+///
+/// Each quantile is tracked with five markers in constant memory, without
+/// storing or sorting the observations. The estimates are approximate:
+/// for smooth distributions they typically settle within a few percent
+/// of the true quantiles after a few hundred observations, but there is
+/// no hard error bound, and extreme quantiles, heavy tails, and
+/// non-stationary streams converge more slowly. Since each quantile is
+/// estimated independently, the estimates are not guaranteed to be
+/// strictly ascending.
+///
+/// ```
+/// # struct P2Estimator;
+/// pub struct StreamingQuantileBucketizer {
+///     estimators: Vec<P2Estimator>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamingQuantileBucketizer {
+    estimators: Vec<P2Estimator>,
+}
+
+impl StreamingQuantileBucketizer {
+    /// Creates a new `StreamingQuantileBucketizer` estimating the given
+    /// quantiles, which form the boundaries of `quantiles.len() + 1`
+    /// buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantiles` - The ascending quantiles to estimate, each strictly between 0 and 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a quantile is not strictly between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::streaming_quantile::StreamingQuantileBucketizer;
+    ///
+    /// let bucketizer = StreamingQuantileBucketizer::new(vec![0.25, 0.5, 0.75]);
+    ///
+    /// assert!(bucketizer.estimates().is_empty());
+    /// ```
+    pub fn new(quantiles: Vec<f64>) -> Self {
+        for p in quantiles.iter() {
+            assert!(*p > 0.0 && *p < 1.0, "quantiles must be strictly between 0 and 1");
+        }
+
+        StreamingQuantileBucketizer {
+            estimators: quantiles.into_iter().map(P2Estimator::new).collect(),
+        }
+    }
+
+    /// Updates the quantile estimates with a new observation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::streaming_quantile::StreamingQuantileBucketizer;
+    ///
+    /// let mut bucketizer = StreamingQuantileBucketizer::new(vec![0.5]);
+    ///
+    /// // A shuffled stream of 0..10_000
+    /// for i in 0..10_000u64 {
+    ///     bucketizer.update(((i * 7_919) % 10_000) as f64);
+    /// }
+    ///
+    /// let median = bucketizer.estimates()[0];
+    /// assert!((median - 4_999.5).abs() < 100.0, "estimated median {}", median);
+    /// ```
+    pub fn update(&mut self, value: f64) {
+        for estimator in self.estimators.iter_mut() {
+            estimator.update(value);
+        }
+    }
+
+    /// Returns the current quantile estimates, or an empty vector if no
+    /// values have been observed yet.
+    pub fn estimates(&self) -> Vec<f64> {
+        self.estimators
+            .iter()
+            .filter_map(|estimator| estimator.estimate())
+            .collect()
+    }
+}
+
+impl BucketizeSingle<f64> for StreamingQuantileBucketizer {
+    /// Bucketizes a single value against the current quantile estimates.
+    ///
+    /// The value is assigned to the first bucket whose upper estimate is
+    /// greater than it. Before any values have been observed, every value
+    /// lands in bucket 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{Bucketize, BucketizeSingle};
+    /// use buckets::bucketizers::streaming_quantile::StreamingQuantileBucketizer;
+    ///
+    /// let mut bucketizer = StreamingQuantileBucketizer::new(vec![0.25, 0.5, 0.75]);
+    /// assert_eq!(bucketizer.bucketize(&42.0), 0);
+    ///
+    /// for i in 0..1_000u64 {
+    ///     bucketizer.update(((i * 389) % 1_000) as f64);
+    /// }
+    ///
+    /// assert_eq!(bucketizer.bucketize_iter(vec![100.0, 400.0, 600.0, 900.0].into_iter()), vec![0, 1, 2, 3]);
+    /// ```
+    fn bucketize(&self, value: &f64) -> usize {
+        // The index of the first estimate above the value is the number of
+        // estimates before it, so count them in place
+        self.estimators
+            .iter()
+            .filter_map(P2Estimator::estimate)
+            .take_while(|estimate| value.partial_cmp(estimate) != Some(Ordering::Less))
+            .count()
+    }
+}
+
+impl<I> Bucketize<f64, I> for StreamingQuantileBucketizer
+where
+    I: Iterator<Item = f64>,
+{}
+
+impl BucketCount for StreamingQuantileBucketizer {
+    fn num_buckets(&self) -> Option<usize> {
+        Some(self.estimators.len() + 1)
+    }
+}
@@ -0,0 +1,128 @@
+use core::error::Error;
+use core::fmt;
+use crate::bucketize::BucketizeSingle;
+use crate::bucketizers::fw::FixedWidthBucketizer;
+use crate::bucketizers::linear::{LinearBucketizer, LinearError};
+use crate::bucketizers::quantile::QuantileBucketizer;
+use crate::bucketizers::range::{RangeBucketizer, RangeError};
+
+/// The reasons a `BucketizerSpec` can be rejected by
+/// `BucketizerSpec::build`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpecError {
+    /// The parameters of a linear spec were rejected.
+    Linear(LinearError),
+    /// The ranges of a range spec were rejected.
+    Range(RangeError),
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecError::Linear(error) => write!(f, "invalid linear bucketizer: {}", error),
+            SpecError::Range(error) => write!(f, "invalid range bucketizer: {}", error),
+        }
+    }
+}
+
+impl Error for SpecError {}
+
+/// A serializable description of a bucketizer over `f64` values, so the
+/// choice of bucketizer and its parameters can be driven by a config file.
+///
+/// The variant is selected by a `"type"` field holding its snake case
+/// name, alongside the variant's parameters.
+///
+/// # Example
+///
+/// ```
+/// use buckets::config::BucketizerSpec;
+///
+/// let spec: BucketizerSpec = serde_json::from_str(
+///     r#"{ "type": "linear", "start": 0.0, "end": 20.0, "num_buckets": 4 }"#,
+/// ).unwrap();
+///
+/// assert_eq!(spec, BucketizerSpec::Linear { start: 0.0, end: 20.0, num_buckets: 4 });
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BucketizerSpec {
+    /// A `LinearBucketizer` with `num_buckets` buckets from `start` to `end`.
+    Linear {
+        start: f64,
+        end: f64,
+        num_buckets: usize,
+    },
+    /// A `FixedWidthBucketizer`, optionally capped at `max_buckets`.
+    FixedWidth {
+        width: f64,
+        offset: f64,
+        #[serde(default)]
+        max_buckets: Option<usize>,
+    },
+    /// A `RangeBucketizer` over `[start, end)` ranges.
+    Range {
+        ranges: Vec<(f64, f64)>,
+    },
+    /// A `QuantileBucketizer` with the given cut points.
+    Quantile {
+        quantiles: Vec<f64>,
+        n_quantiles: usize,
+    },
+}
+
+impl BucketizerSpec {
+    /// Builds the bucketizer described by the spec.
+    ///
+    /// Specs usually come from untrusted config files, so linear and range
+    /// specs are validated with `LinearBucketizer::try_new` and
+    /// `RangeBucketizer::try_new` rather than building a bucketizer that
+    /// panics on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpecError` if the parameters of the spec are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::linear::LinearError;
+    /// use buckets::config::{BucketizerSpec, SpecError};
+    ///
+    /// let spec: BucketizerSpec = serde_json::from_str(
+    ///     r#"{ "type": "range", "ranges": [[0.0, 5.0], [5.0, 10.0], [10.0, 20.0]] }"#,
+    /// ).unwrap();
+    /// let bucketizer = spec.build().unwrap();
+    ///
+    /// assert_eq!(bucketizer.bucketize(&7.0), 1);
+    ///
+    /// let spec = BucketizerSpec::Linear { start: 0.0, end: 20.0, num_buckets: 0 };
+    /// assert_eq!(spec.build().err(), Some(SpecError::Linear(LinearError::ZeroBuckets)));
+    /// ```
+    pub fn build(&self) -> Result<Box<dyn BucketizeSingle<f64>>, SpecError> {
+        let bucketizer = match self {
+            BucketizerSpec::Linear { start, end, num_buckets } => {
+                LinearBucketizer::try_new(*start, *end, *num_buckets as f64)
+                    .map_err(SpecError::Linear)?
+                    .boxed()
+            }
+            BucketizerSpec::FixedWidth { width, offset, max_buckets } => {
+                let bucketizer = FixedWidthBucketizer::new(*width, *offset);
+                match max_buckets {
+                    Some(n) => bucketizer.with_max_buckets(*n).boxed(),
+                    None => bucketizer.boxed(),
+                }
+            }
+            BucketizerSpec::Range { ranges } => {
+                RangeBucketizer::try_new(ranges.clone())
+                    .map_err(SpecError::Range)?
+                    .boxed()
+            }
+            BucketizerSpec::Quantile { quantiles, n_quantiles } => {
+                QuantileBucketizer::new(quantiles.clone(), *n_quantiles).boxed()
+            }
+        };
+
+        Ok(bucketizer)
+    }
+}
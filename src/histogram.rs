@@ -0,0 +1,436 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Div};
+use num_traits::FromPrimitive;
+use crate::bucketize::BucketizeSingle;
+
+/// A streaming histogram that bucketizes values as they arrive and
+/// keeps a running count per bucket, so large streams never need to
+/// be materialized.
+///
+/// ```
+/// pub struct HistogramAccumulator<B> {
+///     bucketizer: B,
+///     counts: Vec<usize>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HistogramAccumulator<B> {
+    bucketizer: B,
+    counts: Vec<usize>,
+}
+
+impl<B> HistogramAccumulator<B> {
+    /// Creates a new, empty `HistogramAccumulator` around `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::HistogramAccumulator;
+    ///
+    /// let histogram = HistogramAccumulator::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// assert!(histogram.counts().is_empty());
+    /// ```
+    pub fn new(bucketizer: B) -> Self {
+        HistogramAccumulator { bucketizer, counts: Vec::new() }
+    }
+
+    /// Creates a new `HistogramAccumulator` and pushes every item of `iter`
+    /// into it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::HistogramAccumulator;
+    ///
+    /// let data = vec![1, 1, 6, 11];
+    /// let histogram = HistogramAccumulator::from_iter(
+    ///     FixedWidthBucketizer::new(5, 0),
+    ///     data.into_iter(),
+    /// );
+    ///
+    /// assert_eq!(histogram.counts(), &[2, 1, 1]);
+    /// ```
+    pub fn from_iter<T, I>(bucketizer: B, iter: I) -> Self
+    where
+        T: PartialOrd + Copy,
+        B: BucketizeSingle<T>,
+        I: Iterator<Item = T>,
+    {
+        let mut histogram = HistogramAccumulator::new(bucketizer);
+        for value in iter {
+            histogram.push(&value);
+        }
+        histogram
+    }
+
+    /// Bucketizes `value` and increments the count of its bucket, growing
+    /// the counts as needed. Returns the bucket `value` was assigned to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::HistogramAccumulator;
+    ///
+    /// let mut histogram = HistogramAccumulator::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// // The first value lands in bucket 5, before any lower bucket is seen
+    /// assert_eq!(histogram.push(&27), 5);
+    /// assert_eq!(histogram.push(&3), 0);
+    /// histogram.push(&26);
+    ///
+    /// assert_eq!(histogram.counts(), &[1, 0, 0, 0, 0, 2]);
+    /// ```
+    pub fn push<T>(&mut self, value: &T) -> usize
+    where
+        T: PartialOrd + Copy,
+        B: BucketizeSingle<T>,
+    {
+        let bucket = self.bucketizer.bucketize(value);
+        if bucket >= self.counts.len() {
+            self.counts.resize(bucket + 1, 0);
+        }
+        self.counts[bucket] += 1;
+        bucket
+    }
+
+    /// Returns the count of each bucket observed so far, where element
+    /// `i` is the count for bucket `i`.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+}
+
+/// A streaming histogram that sums a weight per bucket rather than 
+/// counting values, for weighted samples.
+///
+/// ```
+/// pub struct WeightedHistogram<B> {
+///     bucketizer: B,
+///     weights: Vec<f64>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WeightedHistogram<B> {
+    bucketizer: B,
+    weights: Vec<f64>,
+}
+
+impl<B> WeightedHistogram<B> {
+    /// Creates a new, empty `WeightedHistogram` around `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::WeightedHistogram;
+    ///
+    /// let histogram = WeightedHistogram::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// assert!(histogram.weights().is_empty());
+    /// ```
+    pub fn new(bucketizer: B) -> Self {
+        WeightedHistogram { bucketizer, weights: Vec::new() }
+    }
+
+    /// Creates a new `WeightedHistogram` and pushes every `(value, weight)` 
+    /// pair of `iter` into it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::WeightedHistogram;
+    ///
+    /// let samples = vec![(6, 0.5), (7, 1.5), (9, 2.0), (11, 1.0)];
+    /// let histogram = WeightedHistogram::from_iter(
+    ///     FixedWidthBucketizer::new(5, 0),
+    ///     samples.into_iter(),
+    /// );
+    ///
+    /// assert_eq!(histogram.weights(), &[0.0, 4.0, 1.0]);
+    /// ```
+    pub fn from_iter<T, I>(bucketizer: B, iter: I) -> Self
+    where
+        T: PartialOrd + Copy,
+        B: BucketizeSingle<T>,
+        I: Iterator<Item = (T, f64)>,
+    {
+        let mut histogram = WeightedHistogram::new(bucketizer);
+        for (value, weight) in iter {
+            histogram.push(&value, weight);
+        }
+        histogram
+    }
+
+    /// Bucketizes `value` and adds `weight` to its bucket's total, growing 
+    /// the totals as needed. Returns the bucket `value` was assigned to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::WeightedHistogram;
+    ///
+    /// let mut histogram = WeightedHistogram::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// assert_eq!(histogram.push(&12, 0.25), 2);
+    /// histogram.push(&14, 0.5);
+    ///
+    /// assert_eq!(histogram.weights(), &[0.0, 0.0, 0.75]);
+    /// ```
+    pub fn push<T>(&mut self, value: &T, weight: f64) -> usize
+    where
+        T: PartialOrd + Copy,
+        B: BucketizeSingle<T>,
+    {
+        let bucket = self.bucketizer.bucketize(value);
+        if bucket >= self.weights.len() {
+            self.weights.resize(bucket + 1, 0.0);
+        }
+        self.weights[bucket] += weight;
+        bucket
+    }
+
+    /// Returns the summed weight of each bucket observed so far, where 
+    /// element `i` is the total for bucket `i`.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+/// The running summary of the values in one bucket of `BucketStats`.
+#[derive(Clone, Debug)]
+struct Summary<T> {
+    count: usize,
+    min: T,
+    max: T,
+    sum: T,
+}
+
+/// A streaming accumulator that tracks the count, minimum, maximum and 
+/// mean of the original values that land in each bucket.
+///
+/// ```
+/// # struct Summary<T>(T);
+/// pub struct BucketStats<T, B> {
+///     bucketizer: B,
+///     summaries: Vec<Option<Summary<T>>>,
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BucketStats<T, B> {
+    bucketizer: B,
+    summaries: Vec<Option<Summary<T>>>,
+}
+
+impl<T, B> BucketStats<T, B>
+where
+    T: PartialOrd + Copy + Add<Output = T>,
+    B: BucketizeSingle<T>,
+{
+    /// Creates a new, empty `BucketStats` around `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::BucketStats;
+    ///
+    /// let stats = BucketStats::<i32, _>::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// assert_eq!(stats.count(0), 0);
+    /// assert_eq!(stats.min(0), None);
+    /// ```
+    pub fn new(bucketizer: B) -> Self {
+        BucketStats { bucketizer, summaries: Vec::new() }
+    }
+
+    /// Creates a new `BucketStats` and pushes every item of `iter` into it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::BucketStats;
+    ///
+    /// let data = vec![1.0, 3.0, 2.0, 6.0, 9.0, 12.0];
+    /// let stats = BucketStats::from_iter(FixedWidthBucketizer::new(5.0, 0.0), data.into_iter());
+    ///
+    /// assert_eq!((stats.count(0), stats.min(0), stats.max(0), stats.mean(0)), (3, Some(1.0), Some(3.0), Some(2.0)));
+    /// assert_eq!((stats.count(1), stats.min(1), stats.max(1), stats.mean(1)), (2, Some(6.0), Some(9.0), Some(7.5)));
+    /// assert_eq!((stats.count(2), stats.min(2), stats.max(2), stats.mean(2)), (1, Some(12.0), Some(12.0), Some(12.0)));
+    /// assert_eq!((stats.count(3), stats.mean(3)), (0, None));
+    /// ```
+    pub fn from_iter<I>(bucketizer: B, iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        let mut stats = BucketStats::new(bucketizer);
+        for value in iter {
+            stats.push(&value);
+        }
+        stats
+    }
+
+    /// Bucketizes `value` and folds it into its bucket's summary, growing 
+    /// the summaries as needed. Returns the bucket `value` was assigned to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::BucketStats;
+    ///
+    /// let mut stats = BucketStats::new(FixedWidthBucketizer::new(5, 0));
+    ///
+    /// assert_eq!(stats.push(&12), 2);
+    /// stats.push(&10);
+    ///
+    /// assert_eq!(stats.min(2), Some(10));
+    /// assert_eq!(stats.max(2), Some(12));
+    /// ```
+    pub fn push(&mut self, value: &T) -> usize {
+        let bucket = self.bucketizer.bucketize(value);
+        if bucket >= self.summaries.len() {
+            self.summaries.resize_with(bucket + 1, || None);
+        }
+
+        let value = *value;
+        match &mut self.summaries[bucket] {
+            Some(summary) => {
+                summary.count += 1;
+                if value < summary.min {
+                    summary.min = value;
+                }
+                if value > summary.max {
+                    summary.max = value;
+                }
+                summary.sum = summary.sum + value;
+            }
+            empty => {
+                *empty = Some(Summary { count: 1, min: value, max: value, sum: value });
+            }
+        }
+        bucket
+    }
+}
+
+impl<T: Copy, B> BucketStats<T, B> {
+    /// Returns the number of values that landed in `bucket`.
+    pub fn count(&self, bucket: usize) -> usize {
+        self.summary(bucket).map_or(0, |summary| summary.count)
+    }
+
+    /// Returns the smallest value that landed in `bucket`, or `None` if 
+    /// the bucket is empty.
+    pub fn min(&self, bucket: usize) -> Option<T> {
+        self.summary(bucket).map(|summary| summary.min)
+    }
+
+    /// Returns the largest value that landed in `bucket`, or `None` if 
+    /// the bucket is empty.
+    pub fn max(&self, bucket: usize) -> Option<T> {
+        self.summary(bucket).map(|summary| summary.max)
+    }
+
+    /// Returns the mean of the values that landed in `bucket`, or `None` 
+    /// if the bucket is empty. For integer types the mean is truncated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::histogram::BucketStats;
+    ///
+    /// let stats = BucketStats::from_iter(FixedWidthBucketizer::new(5, 0), vec![1, 2].into_iter());
+    ///
+    /// assert_eq!(stats.mean(0), Some(1));
+    /// ```
+    pub fn mean(&self, bucket: usize) -> Option<T>
+    where
+        T: Div<Output = T> + FromPrimitive,
+    {
+        let summary = self.summary(bucket)?;
+        Some(summary.sum / T::from_usize(summary.count)?)
+    }
+
+    fn summary(&self, bucket: usize) -> Option<&Summary<T>> {
+        self.summaries.get(bucket)?.as_ref()
+    }
+}
+
+/// Rescales `counts` into relative frequencies that sum to 1.0.
+///
+/// If the total count is zero, every frequency is 0.0 rather than `NaN`.
+///
+/// # Example
+///
+/// ```
+/// use buckets::histogram::normalize;
+///
+/// assert_eq!(normalize(&[1, 1, 2]), vec![0.25, 0.25, 0.5]);
+/// assert_eq!(normalize(&[0, 0, 0]), vec![0.0, 0.0, 0.0]);
+/// assert!(normalize(&[]).is_empty());
+/// ```
+pub fn normalize(counts: &[usize]) -> Vec<f64> {
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return vec![0.0; counts.len()];
+    }
+
+    counts
+        .iter()
+        .map(|&count| count as f64 / total as f64)
+        .collect()
+}
+
+/// Returns the cumulative counts of `counts`, where element `i` is the 
+/// number of values in buckets `0..=i`, e.g. to build an empirical CDF.
+///
+/// # Example
+///
+/// ```
+/// use buckets::histogram::cumulative;
+///
+/// assert_eq!(cumulative(&[1, 2, 3]), vec![1, 3, 6]);
+/// assert!(cumulative(&[]).is_empty());
+/// ```
+pub fn cumulative(counts: &[usize]) -> Vec<usize> {
+    counts
+        .iter()
+        .scan(0, |total, &count| {
+            *total += count;
+            Some(*total)
+        })
+        .collect()
+}
+
+
+/// Sums histograms element-wise, e.g. to combine the counts computed by 
+/// several workers with the same bucketizer. Shorter histograms are 
+/// treated as having zero counts in their missing trailing buckets.
+///
+/// # Example
+///
+/// ```
+/// use buckets::histogram::merge_histograms;
+///
+/// let merged = merge_histograms(&[vec![1, 2], vec![0, 1, 4, 2], vec![3]]);
+///
+/// assert_eq!(merged, vec![4, 3, 4, 2]);
+/// assert!(merge_histograms(&[]).is_empty());
+/// ```
+pub fn merge_histograms(hists: &[Vec<usize>]) -> Vec<usize> {
+    let len = hists.iter().map(Vec::len).max().unwrap_or(0);
+    let mut merged = vec![0; len];
+    for hist in hists {
+        for (total, &count) in merged.iter_mut().zip(hist) {
+            *total += count;
+        }
+    }
+    merged
+}
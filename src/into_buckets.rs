@@ -1,4 +1,6 @@
-use crate::bucketize::Bucketize;
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+use crate::bucketize::{BucketBounds, Bucketize, BucketizeSingle};
 /// A type to convert into when bucketizing 
 ///
 /// ```
@@ -72,4 +74,277 @@ where
             }
         )
     }
+
+    /// Returns the bounds on the remaining length of the inner iterator,
+    /// since exactly one bucket is produced for every input value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::into_buckets::IntoBuckets;
+    ///
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    /// let bucketized = IntoBuckets::new(vec![1, 2, 3].into_iter(), bucketizer);
+    ///
+    /// assert_eq!(bucketized.size_hint(), (3, Some(3)));
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// `IntoBuckets` yields exactly one bucket per input value, so its length
+/// is known whenever the inner iterator's length is.
+///
+/// # Example
+///
+/// ```
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::into_buckets::IntoBuckets;
+///
+/// let bucketizer = FixedWidthBucketizer::new(5, 0);
+/// let bucketized = IntoBuckets::new(vec![1, 2, 3].into_iter(), bucketizer);
+///
+/// assert_eq!(bucketized.len(), 3);
+/// ```
+impl<I, T, B> ExactSizeIterator for IntoBuckets<I, B>
+where
+    I: ExactSizeIterator<Item = T>,
+    B: Bucketize<T, I>,
+    T: PartialOrd + Default + Copy,
+{}
+
+/// An iterator adapter like `IntoBuckets` that yields each bucket index 
+/// together with the bounds of that bucket, e.g. for plotting.
+///
+/// ```
+/// pub struct IntoBucketsWithBounds<I, B> {
+///     inner: I,
+///     bucketizer: B,
+/// }
+/// ```
+pub struct IntoBucketsWithBounds<I, B> {
+    inner: I,
+    bucketizer: B,
 }
+
+impl<I, B> IntoBucketsWithBounds<I, B> {
+    /// Creates a new `IntoBucketsWithBounds` iterator by wrapping the input 
+    /// iterator `inner` and applying the provided bucketizer `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketize::{BucketBounds, BucketizeSingle};
+    /// use buckets::bucketizers::linear::LinearBucketizer;
+    /// use buckets::into_buckets::IntoBucketsWithBounds;
+    ///
+    /// let data = vec![1.0, 7.0, 12.5, 19.0, 30.0];
+    /// let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+    ///
+    /// let bucketized: Vec<(usize, (f64, f64))> =
+    ///     IntoBucketsWithBounds::new(data.clone().into_iter(), bucketizer.clone()).collect();
+    ///
+    /// assert_eq!(bucketized[1], (1, (5.0, 10.0)));
+    /// for (value, (index, bounds)) in data.iter().zip(bucketized) {
+    ///     assert_eq!(index, bucketizer.bucketize(value));
+    ///     assert_eq!(Some(bounds), bucketizer.bucket_bounds(index));
+    /// }
+    /// ```
+    pub fn new(inner: I, bucketizer: B) -> Self {
+        IntoBucketsWithBounds { inner, bucketizer }
+    }
+}
+
+impl<I, T, B> Iterator for IntoBucketsWithBounds<I, B>
+where
+    I: Iterator<Item = T>,
+    B: BucketizeSingle<T> + BucketBounds<T>,
+    T: PartialOrd + Copy,
+{
+    type Item = (usize, (T, T));
+
+    /// Returns the next bucket index and its bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bucketizer assigns a value to a bucket for which 
+    /// `bucket_bounds` returns `None`.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|value| {
+            let index = self.bucketizer.bucketize(&value);
+            let bounds = self.bucketizer
+                .bucket_bounds(index)
+                .expect("bucketizer assigned a value to a bucket without bounds");
+            (index, bounds)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, T, B> ExactSizeIterator for IntoBucketsWithBounds<I, B>
+where
+    I: ExactSizeIterator<Item = T>,
+    B: BucketizeSingle<T> + BucketBounds<T>,
+    T: PartialOrd + Copy,
+{}
+
+/// Convenience methods for consuming an iterator of bucket indices, 
+/// such as an `IntoBuckets`.
+///
+/// It is implemented for every `Iterator<Item = usize>`.
+pub trait BucketIteratorExt: Iterator<Item = usize> + Sized {
+    /// Consumes the iterator and returns the number of occurrences of each 
+    /// bucket index. The result has at least `num_buckets` elements and 
+    /// grows if a higher index is encountered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::into_buckets::{BucketIteratorExt, IntoBuckets};
+    ///
+    /// let data = vec![1, 2, 6, 11, 12, 13];
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// let counts = IntoBuckets::new(data.into_iter(), bucketizer).counts(4);
+    ///
+    /// assert_eq!(counts, vec![2, 1, 3, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn counts(self, num_buckets: usize) -> Vec<usize> {
+        let mut counts = vec![0; num_buckets];
+        for bucket in self {
+            if bucket >= counts.len() {
+                counts.resize(bucket + 1, 0);
+            }
+            counts[bucket] += 1;
+        }
+        counts
+    }
+
+    /// Consumes the iterator and returns the most frequent bucket index, 
+    /// preferring the lowest index on ties. Returns `None` for an empty 
+    /// iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::into_buckets::{BucketIteratorExt, IntoBuckets};
+    ///
+    /// let data = vec![1, 2, 6, 11, 12, 13];
+    /// let bucketizer = FixedWidthBucketizer::new(5, 0);
+    ///
+    /// assert_eq!(IntoBuckets::new(data.into_iter(), bucketizer).most_common(), Some(2));
+    /// assert_eq!(Vec::<usize>::new().into_iter().most_common(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn most_common(self) -> Option<usize> {
+        let mut counts = BTreeMap::new();
+        for bucket in self {
+            *counts.entry(bucket).or_insert(0usize) += 1;
+        }
+        counts
+            .into_iter()
+            .max_by(|(a_index, a_count), (b_index, b_count)| {
+                a_count.cmp(b_count).then(b_index.cmp(a_index))
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+impl<I: Iterator<Item = usize>> BucketIteratorExt for I {}
+
+/// An iterator adapter that maps a stream of bucket indices to their 
+/// labels, falling back to a default label for indices without one.
+///
+/// ```
+/// pub struct BucketsToLabels<I> {
+///     inner: I,
+///     labels: Vec<String>,
+///     fallback: String,
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct BucketsToLabels<I> {
+    inner: I,
+    labels: Vec<String>,
+    fallback: String,
+}
+
+#[cfg(feature = "alloc")]
+impl<I> BucketsToLabels<I> {
+    /// Creates a new `BucketsToLabels` iterator yielding `labels[i]` for 
+    /// every index `i` of `inner`, or `fallback` if `i` has no label.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::into_buckets::{BucketsToLabels, IntoBuckets};
+    ///
+    /// let labels = vec!["low".to_string(), "mid".to_string(), "high".to_string()];
+    ///
+    /// let labeled: Vec<String> = BucketsToLabels::new(vec![0, 1, 2, 7].into_iter(), labels.clone(), "other").collect();
+    /// assert_eq!(labeled, vec!["low", "mid", "high", "other"]);
+    ///
+    /// // Composes with `IntoBuckets`
+    /// let bucketized = IntoBuckets::new(vec![1, 12, 6].into_iter(), FixedWidthBucketizer::new(5, 0));
+    /// let labeled: Vec<String> = BucketsToLabels::new(bucketized, labels, "other").collect();
+    /// assert_eq!(labeled, vec!["low", "high", "mid"]);
+    /// ```
+    pub fn new(inner: I, labels: Vec<String>, fallback: impl Into<String>) -> Self {
+        BucketsToLabels { inner, labels, fallback: fallback.into() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = usize>> Iterator for BucketsToLabels<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|index| {
+            self.labels
+                .get(index)
+                .unwrap_or(&self.fallback)
+                .clone()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ExactSizeIterator<Item = usize>> ExactSizeIterator for BucketsToLabels<I> {}
+
+/// Fluent syntax for bucketizing any iterator, as sugar over
+/// `IntoBuckets::new`.
+///
+/// It is implemented for every `Iterator`.
+pub trait BucketizeIterExt: Iterator + Sized {
+    /// Wraps the iterator in an `IntoBuckets` that bucketizes each item
+    /// with `bucketizer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use buckets::bucketizers::fw::FixedWidthBucketizer;
+    /// use buckets::into_buckets::BucketizeIterExt;
+    ///
+    /// let fw = FixedWidthBucketizer::new(5.0, 0.0);
+    ///
+    /// assert_eq!(vec![1.0, 6.0].into_iter().bucketize(fw).collect::<Vec<_>>(), vec![0, 1]);
+    /// ```
+    fn bucketize<B>(self, bucketizer: B) -> IntoBuckets<Self, B> {
+        IntoBuckets::new(self, bucketizer)
+    }
+}
+
+impl<I: Iterator> BucketizeIterExt for I {}
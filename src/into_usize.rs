@@ -1,3 +1,9 @@
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use core::ops::{Add, Sub, Mul, Div};
+use core::time::Duration;
 use ordered_float::OrderedFloat;
 use num_traits::AsPrimitive;
 
@@ -13,16 +19,159 @@ macro_rules! impl_into_usize_for_numeric_types {
     };
 }
 
+macro_rules! impl_into_usize_for_non_zero_types {
+    ($($t:ty),*) => {
+        $(
+            impl IntoUsize for $t {
+                fn into_usize(&self) -> usize {
+                    self.get().into_usize()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_saturating_into_usize_for_integer_types {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingIntoUsize for $t {
+                fn saturating_into_usize(&self) -> usize {
+                    usize::try_from(*self)
+                        .unwrap_or(if *self < (0 as $t) { 0 } else { usize::MAX })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_saturating_into_usize_for_float_types {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingIntoUsize for $t {
+                fn saturating_into_usize(&self) -> usize {
+                    // Float to integer `as` casts saturate, and map NaN to 0
+                    *self as usize
+                }
+            }
+        )*
+    };
+}
+
+/// A conversion into `usize`, used to turn the result of a bucketizer's
+/// arithmetic into a bucket index.
+///
+/// Primitive integers and floats, including `isize` and `usize` itself,
+/// convert with an `as` cast, except `u128` and `i128`, which saturate at
+/// `usize::MAX`. The `NonZero*` integer
+/// types convert like their primitive counterparts, so IDs stored as e.g.
+/// `NonZeroU64` can be bucketized without unwrapping them first. As with
+/// the primitives, `NonZeroU64` and `NonZeroI64` values above `u32::MAX`
+/// are narrowed on 32-bit targets, while `NonZeroU128` and `NonZeroI128`
+/// saturate.
+///
+/// ```
+/// use std::num::{NonZeroU32, NonZeroU64};
+/// use buckets::into_usize::IntoUsize;
+///
+/// assert_eq!(usize::MAX.into_usize(), usize::MAX);
+/// assert_eq!(42isize.into_usize(), 42);
+/// assert_eq!((-1isize).into_usize(), usize::MAX);
+///
+/// assert_eq!(NonZeroU32::new(42).unwrap().into_usize(), 42);
+/// assert_eq!(NonZeroU32::MAX.into_usize(), u32::MAX as usize);
+/// assert_eq!(NonZeroU64::new(7).unwrap().into_usize(), 7);
+/// # #[cfg(target_pointer_width = "64")]
+/// assert_eq!(NonZeroU64::MAX.into_usize(), usize::MAX);
+/// ```
 pub trait IntoUsize {
+    #[allow(clippy::wrong_self_convention)]
     fn into_usize(&self) -> usize;
 }
 
 impl_into_usize_for_numeric_types! {
-    i8, i16, i32, i64, i128,
-    u8, u16, u32, u64, u128,
+    i8, i16, i32, i64, isize,
+    u8, u16, u32, u64, usize,
     f32, f64
 }
 
+/// Converts a `u128` to `usize`, saturating at `usize::MAX` instead of
+/// silently narrowing.
+///
+/// A plain `as usize` cast keeps only the low bits, so a value just past
+/// `usize::MAX` would wrap around to a small bucket index.
+///
+/// ```
+/// use buckets::into_usize::IntoUsize;
+///
+/// assert_eq!(42u128.into_usize(), 42);
+/// assert_eq!(u128::MAX.into_usize(), usize::MAX);
+/// assert_eq!((usize::MAX as u128 + 2).into_usize(), usize::MAX);
+/// ```
+impl IntoUsize for u128 {
+    fn into_usize(&self) -> usize {
+        usize::try_from(*self).unwrap_or(usize::MAX)
+    }
+}
+
+/// Converts an `i128` to `usize`, saturating at `usize::MAX` for values
+/// that are too large instead of silently narrowing.
+///
+/// Negative values wrap like the other signed integers, so an `i128`
+/// converts exactly like an `i64` of the same value. Use `Saturating` to
+/// clamp them to 0 instead.
+///
+/// ```
+/// use buckets::into_usize::IntoUsize;
+///
+/// assert_eq!(42i128.into_usize(), 42);
+/// assert_eq!(i128::MAX.into_usize(), usize::MAX);
+/// assert_eq!((usize::MAX as i128 + 2).into_usize(), usize::MAX);
+/// assert_eq!((-1i128).into_usize(), (-1i64).into_usize());
+/// ```
+impl IntoUsize for i128 {
+    fn into_usize(&self) -> usize {
+        if *self < 0 {
+            *self as usize
+        } else {
+            usize::try_from(*self).unwrap_or(usize::MAX)
+        }
+    }
+}
+
+impl_into_usize_for_non_zero_types! {
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize
+}
+
+/// Converts `false` to 0 and `true` to 1.
+///
+/// ```
+/// use buckets::into_usize::IntoUsize;
+///
+/// assert_eq!(false.into_usize(), 0);
+/// assert_eq!(true.into_usize(), 1);
+/// ```
+impl IntoUsize for bool {
+    fn into_usize(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Converts a `char` to its Unicode scalar value.
+///
+/// ```
+/// use buckets::into_usize::IntoUsize;
+///
+/// assert_eq!('a'.into_usize(), 97);
+/// assert_eq!('é'.into_usize(), 0xE9);
+/// assert_eq!('€'.into_usize(), 0x20AC);
+/// ```
+impl IntoUsize for char {
+    fn into_usize(&self) -> usize {
+        *self as usize
+    }
+}
+
 impl IntoUsize for OrderedFloat<f64> {
     fn into_usize(&self) -> usize {
         self.as_()
@@ -34,3 +183,202 @@ impl IntoUsize for OrderedFloat<f32> {
         self.as_()
     }
 }
+
+/// Converts a `Duration` into whole milliseconds, saturating at
+/// `usize::MAX`. Sub-millisecond durations round down to 0.
+///
+/// `Duration` supports `Sub` but not `Div<Duration>`, so it cannot be used
+/// as `T` for `FixedWidthBucketizer` or `LinearBucketizer`. It works
+/// directly with `RangeBucketizer`, `QuantileBucketizer` and
+/// `CustomBucketizer`, which only require `PartialOrd + Copy`.
+///
+/// ```
+/// use std::time::Duration;
+/// use buckets::into_usize::IntoUsize;
+///
+/// assert_eq!(Duration::from_micros(999).into_usize(), 0);
+/// assert_eq!(Duration::from_millis(1).into_usize(), 1);
+/// assert_eq!(Duration::from_micros(2_500).into_usize(), 2);
+/// assert_eq!(Duration::from_secs(3).into_usize(), 3_000);
+/// assert_eq!(Duration::MAX.into_usize(), usize::MAX);
+/// ```
+impl IntoUsize for Duration {
+    fn into_usize(&self) -> usize {
+        usize::try_from(self.as_millis()).unwrap_or(usize::MAX)
+    }
+}
+
+/// Converts a reference by delegating to the referenced value, so generic
+/// code over borrowed values does not have to dereference them first.
+///
+/// ```
+/// use buckets::into_usize::IntoUsize;
+///
+/// let readings = [2.5f64, 7.9];
+/// let counts = [3i32, 12];
+///
+/// assert_eq!((&readings[1]).into_usize(), 7);
+/// assert_eq!((&counts[1]).into_usize(), 12);
+///
+/// fn sum_all<T: IntoUsize>(values: impl Iterator<Item = T>) -> usize {
+///     values.map(|value| value.into_usize()).sum()
+/// }
+/// assert_eq!(sum_all(readings.iter()), 9);
+/// assert_eq!(sum_all(counts.iter()), 15);
+/// ```
+impl<T: IntoUsize + ?Sized> IntoUsize for &T {
+    fn into_usize(&self) -> usize {
+        (**self).into_usize()
+    }
+}
+
+/// A conversion into `usize` that saturates instead of wrapping, so 
+/// negative values become 0 and values above `usize::MAX` become 
+/// `usize::MAX`.
+///
+/// ```
+/// use buckets::into_usize::{IntoUsize, SaturatingIntoUsize};
+///
+/// assert_eq!((-5i32).saturating_into_usize(), 0);
+/// assert_eq!(5i32.saturating_into_usize(), 5);
+/// assert_eq!((-5i32).into_usize(), usize::MAX - 4);
+///
+/// assert_eq!((-0.5f64).saturating_into_usize(), 0);
+/// assert_eq!(f64::NAN.saturating_into_usize(), 0);
+/// assert_eq!(i128::MAX.saturating_into_usize(), usize::MAX);
+/// ```
+pub trait SaturatingIntoUsize {
+    fn saturating_into_usize(&self) -> usize;
+}
+
+impl_saturating_into_usize_for_integer_types! {
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize
+}
+
+impl_saturating_into_usize_for_float_types! {
+    f32, f64
+}
+
+/// A checked conversion into `usize` that returns `None` instead of
+/// wrapping or truncating when the value does not fit, e.g. a negative
+/// value, a `u64` above `u32::MAX` on a 32-bit target, or `NaN`.
+///
+/// Floats are truncated towards zero like an `as` cast, and fit if the
+/// truncated value does.
+///
+/// ```
+/// use buckets::into_usize::TryIntoUsize;
+///
+/// assert_eq!(42u64.try_into_usize(), Some(42));
+/// assert_eq!((-1i64).try_into_usize(), None);
+/// assert_eq!((usize::MAX as u128 + 1).try_into_usize(), None);
+///
+/// // A 64-bit value only fits a 32-bit usize if it is at most u32::MAX
+/// # #[cfg(target_pointer_width = "32")]
+/// assert_eq!(u64::MAX.try_into_usize(), None);
+/// # #[cfg(target_pointer_width = "64")]
+/// assert_eq!(u64::MAX.try_into_usize(), Some(usize::MAX));
+///
+/// assert_eq!(7.9f64.try_into_usize(), Some(7));
+/// assert_eq!((-0.5f64).try_into_usize(), Some(0));
+/// assert_eq!((-1.0f64).try_into_usize(), None);
+/// assert_eq!(f64::NAN.try_into_usize(), None);
+/// assert_eq!(1e30f64.try_into_usize(), None);
+/// ```
+pub trait TryIntoUsize {
+    fn try_into_usize(&self) -> Option<usize>;
+}
+
+macro_rules! impl_try_into_usize_for_integer_types {
+    ($($t:ty),*) => {
+        $(
+            impl TryIntoUsize for $t {
+                fn try_into_usize(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_try_into_usize_for_float_types {
+    ($($t:ty),*) => {
+        $(
+            impl TryIntoUsize for $t {
+                fn try_into_usize(&self) -> Option<usize> {
+                    // `usize::MAX as $t` rounds up to a power of two, so
+                    // it is the first value that does not fit
+                    (*self > -1.0 && *self < usize::MAX as $t).then(|| *self as usize)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_usize_for_integer_types! {
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize
+}
+
+impl_try_into_usize_for_float_types! {
+    f32, f64
+}
+
+/// A wrapper that lets any bucketizer opt into saturating conversion by
+/// using `Saturating<T>` in place of `T`. Its `IntoUsize` implementation
+/// delegates to `SaturatingIntoUsize`.
+///
+/// ```
+/// use buckets::bucketize::BucketizeSingle;
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::into_usize::Saturating;
+///
+/// // A negative width makes values above the offset produce negative indices
+/// let wrapping = FixedWidthBucketizer::new(-5, 0);
+/// let saturating = FixedWidthBucketizer::new(Saturating(-5), Saturating(0));
+///
+/// assert_eq!(wrapping.bucketize(&12), usize::MAX - 1);
+/// assert_eq!(saturating.bucketize(&Saturating(12)), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Saturating<T>(pub T);
+
+impl<T: SaturatingIntoUsize> IntoUsize for Saturating<T> {
+    fn into_usize(&self) -> usize {
+        self.0.saturating_into_usize()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Saturating<T> {
+    type Output = Saturating<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Saturating(self.0 + rhs.0)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Saturating<T> {
+    type Output = Saturating<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Saturating(self.0 - rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul for Saturating<T> {
+    type Output = Saturating<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Saturating(self.0 * rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div for Saturating<T> {
+    type Output = Saturating<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Saturating(self.0 / rhs.0)
+    }
+}
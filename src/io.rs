@@ -0,0 +1,4 @@
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "polars")]
+pub mod polars;
@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+use crate::bucketize::BucketizeSingle;
+
+/// The errors that can occur while bucketizing a CSV column.
+///
+/// Row indices are zero based and count data records only, so the
+/// first record after the header is row 0.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The file could not be opened or is not valid CSV.
+    Csv(::csv::Error),
+    /// The header does not contain the requested column.
+    MissingColumn(String),
+    /// The cell in the requested column is missing or empty.
+    MissingValue { row: usize },
+    /// The cell in the requested column could not be parsed as `f64`.
+    InvalidValue { row: usize, value: String },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(err) => write!(f, "failed to read csv: {}", err),
+            CsvError::MissingColumn(column) => write!(f, "column `{}` not found", column),
+            CsvError::MissingValue { row } => write!(f, "row {} has no value", row),
+            CsvError::InvalidValue { row, value } => {
+                write!(f, "row {} has a non-numeric value `{}`", row, value)
+            }
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Csv(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(err: ::csv::Error) -> Self {
+        CsvError::Csv(err)
+    }
+}
+
+/// Reads the CSV file at `path`, parses every cell of the column named
+/// `column` as `f64`, and bucketizes it with `bucketizer`.
+///
+/// The file must have a header row. Processing stops at the first row
+/// whose cell is missing or cannot be parsed, and the returned error
+/// carries that row's index.
+///
+/// # Example
+///
+/// ```
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::io::csv::{bucketize_column, CsvError};
+///
+/// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+///
+/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/latency.csv");
+/// let binned = bucketize_column(path, "latency_ms", &bucketizer).unwrap();
+/// assert_eq!(binned, vec![0, 1, 1, 2, 0, 5]);
+///
+/// let mut counts = vec![0; 6];
+/// for bucket in binned {
+///     counts[bucket] += 1;
+/// }
+/// assert_eq!(counts, vec![2, 2, 1, 0, 0, 1]);
+///
+/// let malformed = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/latency_malformed.csv");
+/// match bucketize_column(malformed, "latency_ms", &bucketizer) {
+///     Err(CsvError::InvalidValue { row, value }) => {
+///         assert_eq!(row, 1);
+///         assert_eq!(value, "fast");
+///     }
+///     other => panic!("unexpected result: {:?}", other),
+/// }
+///
+/// assert!(matches!(
+///     bucketize_column(path, "missing", &bucketizer),
+///     Err(CsvError::MissingColumn(_)),
+/// ));
+/// ```
+pub fn bucketize_column<P, B>(path: P, column: &str, bucketizer: &B) -> Result<Vec<usize>, CsvError>
+where
+    P: AsRef<Path>,
+    B: BucketizeSingle<f64>,
+{
+    let mut reader = ::csv::Reader::from_path(path)?;
+    let index = reader
+        .headers()?
+        .iter()
+        .position(|header| header == column)
+        .ok_or_else(|| CsvError::MissingColumn(column.to_string()))?;
+
+    let mut buckets = Vec::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let cell = match record.get(index).map(str::trim) {
+            Some(cell) if !cell.is_empty() => cell,
+            _ => return Err(CsvError::MissingValue { row }),
+        };
+        let value: f64 = cell.parse().map_err(|_| CsvError::InvalidValue {
+            row,
+            value: cell.to_string(),
+        })?;
+        buckets.push(bucketizer.bucketize(&value));
+    }
+    Ok(buckets)
+}
@@ -0,0 +1,57 @@
+use polars::prelude::*;
+use crate::bucketize::BucketizeSingle;
+
+/// Casts the numeric `series` to `f64`, bucketizes every value with
+/// `bucketizer`, and returns a `UInt32` series of bucket indices with the
+/// same name and length.
+///
+/// Null values are propagated, so the output has nulls at the same
+/// positions as the input.
+///
+/// # Errors
+///
+/// Returns an error if `series` cannot be cast to `Float64`, or if a
+/// bucket index does not fit in a `u32`.
+///
+/// # Example
+///
+/// ```
+/// use polars::prelude::*;
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::io::polars::bucketize_series;
+///
+/// let latency = Series::new("latency_ms".into(), &[Some(1), Some(6), None, Some(27)]);
+/// let bucketizer = FixedWidthBucketizer::new(5.0, 0.0);
+///
+/// let binned = bucketize_series(&latency, &bucketizer).unwrap();
+///
+/// assert_eq!(binned.name().as_str(), "latency_ms");
+/// assert_eq!(binned.dtype(), &DataType::UInt32);
+/// let values: Vec<Option<u32>> = binned.u32().unwrap().into_iter().collect();
+/// assert_eq!(values, vec![Some(0), Some(1), None, Some(5)]);
+/// ```
+pub fn bucketize_series<B>(series: &Series, bucketizer: &B) -> PolarsResult<Series>
+where
+    B: BucketizeSingle<f64>,
+{
+    let values = series.cast(&DataType::Float64)?;
+
+    let buckets = values
+        .f64()?
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    let bucket = bucketizer.bucketize(&value);
+                    u32::try_from(bucket).map_err(|_| {
+                        PolarsError::ComputeError(
+                            format!("bucket {} does not fit in a u32", bucket).into(),
+                        )
+                    })
+                })
+                .transpose()
+        })
+        .collect::<PolarsResult<UInt32Chunked>>()?;
+
+    Ok(buckets.with_name(series.name().clone()).into_series())
+}
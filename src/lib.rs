@@ -1,8 +1,392 @@
+//! Bucketizers for binning values into indexed buckets.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! `BucketizeSingle` and the bucketizers that need no heap storage are
+//! always available. The `alloc` feature adds the `Vec`-returning methods,
+//! histograms, and the bucketizers that store their boundaries in a `Vec`.
+//! The `std` feature adds the floating point bucketizers and everything
+//! behind the other optional features.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod bucketizers;
 pub mod into_buckets;
 pub mod bucketize;
+#[cfg(feature = "serde")]
+pub mod config;
+#[cfg(feature = "alloc")]
+pub mod histogram;
 pub mod into_usize;
+pub mod numeric;
+#[cfg(feature = "alloc")]
+pub mod transform;
+#[cfg(any(feature = "csv", feature = "polars"))]
+pub mod io;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
+    mod clone {
+        use std::fmt::Debug;
+        use crate::bucketize::BucketizeSingle;
+        use crate::bucketizers::custom::CustomBucketizer;
+        use crate::bucketizers::fw::FixedWidthBucketizer;
+        use crate::bucketizers::geometric::GeometricBucketizer;
+        use crate::bucketizers::linear::LinearBucketizer;
+        use crate::bucketizers::log::LogarithmicBucketizer;
+        use crate::bucketizers::quantile::QuantileBucketizer;
+        use crate::bucketizers::range::RangeBucketizer;
+
+        const SAMPLES: [f64; 8] = [-3.0, 0.0, 1.0, 4.9, 5.0, 12.5, 19.9, 250.0];
+
+        fn assert_clone_agrees<B>(bucketizer: B)
+        where
+            B: BucketizeSingle<f64> + Clone + Debug,
+        {
+            let cloned = bucketizer.clone();
+            assert!(!format!("{:?}", cloned).is_empty());
+
+            for sample in SAMPLES.iter() {
+                assert_eq!(bucketizer.bucketize(sample), cloned.bucketize(sample));
+            }
+        }
+
+        #[test]
+        fn all_bucketizers_clone_identically() {
+            assert_clone_agrees(LinearBucketizer::new(0.0, 20.0, 4.0));
+            assert_clone_agrees(FixedWidthBucketizer::new(5.0, 0.0));
+            assert_clone_agrees(RangeBucketizer::new(vec![(0.0, 5.0), (5.0, 10.0), (10.0, f64::MAX)]));
+            assert_clone_agrees(QuantileBucketizer::new(vec![1.0, 5.0, 15.0], 4));
+            assert_clone_agrees(LogarithmicBucketizer::new(0.0, 10.0, 4));
+            assert_clone_agrees(GeometricBucketizer::new(1.0, 2.0, 4));
+            assert_clone_agrees(CustomBucketizer::new(|value: &f64| if *value < 5.0 { 0 } else { 1 }));
+        }
+    }
+
+    mod nan {
+        use crate::bucketize::{BucketizeSingle, NanError, NanPolicy};
+        use crate::bucketizers::linear::LinearBucketizer;
+        use crate::bucketizers::quantile::QuantileBucketizer;
+
+        fn linear(policy: NanPolicy) -> LinearBucketizer<f64> {
+            LinearBucketizer::new(0.0, 20.0, 4.0).with_nan_policy(policy)
+        }
+
+        fn quantile(policy: NanPolicy) -> QuantileBucketizer<f64> {
+            QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4).with_nan_policy(policy)
+        }
+
+        #[test]
+        fn to_first_assigns_first_bucket() {
+            assert_eq!(linear(NanPolicy::ToFirst).bucketize(&f64::NAN), 0);
+            assert_eq!(quantile(NanPolicy::ToFirst).bucketize(&f64::NAN), 0);
+        }
+
+        #[test]
+        fn to_last_is_the_default() {
+            assert_eq!(LinearBucketizer::new(0.0, 20.0, 4.0).bucketize(&f64::NAN), 3);
+            assert_eq!(QuantileBucketizer::new(vec![25.0, 50.0, 75.0], 4).bucketize(&f64::NAN), 3);
+            assert_eq!(linear(NanPolicy::ToLast).bucketize(&f64::NAN), 3);
+            assert_eq!(quantile(NanPolicy::ToLast).bucketize(&f64::NAN), 3);
+        }
+
+        #[test]
+        fn error_is_reported_by_try_bucketize() {
+            assert_eq!(linear(NanPolicy::Error).try_bucketize(&f64::NAN), Err(NanError));
+            assert_eq!(quantile(NanPolicy::Error).try_bucketize(&f64::NAN), Err(NanError));
+            assert_eq!(linear(NanPolicy::Error).try_bucketize(&12.0), Ok(2));
+        }
+
+        #[test]
+        #[should_panic(expected = "NanPolicy::Error")]
+        fn error_panics_in_bucketize() {
+            linear(NanPolicy::Error).bucketize(&f64::NAN);
+        }
+    }
+
+    mod numeric {
+        use num_traits::FromPrimitive;
+        use crate::bucketize::BucketizeSingle;
+        use crate::bucketizers::fw::FixedWidthBucketizer;
+        use crate::bucketizers::linear::LinearBucketizer;
+        use crate::numeric::Numeric;
+
+        fn bucketize_with<T: Numeric + Default + FromPrimitive>() -> (usize, usize) {
+            let zero = T::from_u8(0).unwrap();
+            let value = T::from_u8(12).unwrap();
+            let linear = LinearBucketizer::new(zero, T::from_u8(20).unwrap(), T::from_u8(4).unwrap());
+            let fixed = FixedWidthBucketizer::new(T::from_u8(5).unwrap(), zero);
+            (linear.bucketize(&value), fixed.bucketize(&value))
+        }
+
+        #[test]
+        fn numeric_bucketizers_construct_for_primitives() {
+            assert_eq!(bucketize_with::<u8>(), (2, 2));
+            assert_eq!(bucketize_with::<i32>(), (2, 2));
+            assert_eq!(bucketize_with::<u64>(), (2, 2));
+            assert_eq!(bucketize_with::<f32>(), (2, 2));
+            assert_eq!(bucketize_with::<f64>(), (2, 2));
+        }
+
+        #[test]
+        fn values_below_the_origin_do_not_underflow() {
+            assert_eq!(LinearBucketizer::<u8>::new(10, 30, 4).bucketize(&3), 0);
+            assert_eq!(LinearBucketizer::<u64>::new(10, 30, 4).bucketize(&0), 0);
+            assert_eq!(LinearBucketizer::<i32>::new(-10, 30, 4).bucketize(&-50), 0);
+            assert_eq!(LinearBucketizer::<f32>::new(10.0, 30.0, 4.0).bucketize(&-3.0), 0);
+
+            assert_eq!(FixedWidthBucketizer::<u16>::new(5, 100).bucketize(&7), 0);
+            assert_eq!(FixedWidthBucketizer::<i64>::new(5, -100).bucketize(&-107), 0);
+            assert_eq!(FixedWidthBucketizer::<f64>::new(0.5, 1.0).bucketize(&0.25), 0);
+        }
+    }
+
+    mod histogram {
+        use crate::bucketize::Bucketize;
+        use crate::bucketizers::linear::LinearBucketizer;
+        use crate::bucketizers::quantile::QuantileBucketizer;
+        use crate::bucketizers::range::RangeBucketizer;
+
+        #[test]
+        fn bounded_histograms_include_empty_trailing_buckets() {
+            let data = vec![1.0, 2.0, 6.0];
+
+            let linear = LinearBucketizer::new(0.0, 20.0, 4.0);
+            assert_eq!(linear.histogram(data.clone().into_iter()), vec![2, 1, 0, 0]);
+
+            let range = RangeBucketizer::new(vec![(0.0, 5.0), (5.0, 10.0), (10.0, 20.0)]);
+            assert_eq!(range.histogram(data.clone().into_iter()), vec![2, 1, 0]);
+
+            let quantile = QuantileBucketizer::new(vec![5.0, 10.0], 3);
+            assert_eq!(quantile.histogram(data.into_iter()), vec![2, 1, 0]);
+        }
+    }
+
+    mod rebin {
+        use crate::bucketizers::fw::FixedWidthBucketizer;
+        use crate::transform::rebin;
+
+        #[test]
+        fn zero_width_unbounded_bucketizer_terminates() {
+            let old = FixedWidthBucketizer::new(5, 0);
+            let degenerate = FixedWidthBucketizer::new(0, 0);
+
+            assert!(rebin(&old, &[3, 4], &degenerate).is_empty());
+        }
+    }
+
+    mod empty_data {
+        use crate::bucketize::DataError;
+        use crate::bucketizers::linear::LinearBucketizer;
+        use crate::bucketizers::quantile::QuantileBucketizer;
+        use crate::bucketizers::range::RangeBucketizer;
+        use crate::bucketizers::robust::RobustBucketizer;
+
+        #[test]
+        fn data_driven_constructors_reject_empty_data() {
+            let empty: [f64; 0] = [];
+
+            assert_eq!(LinearBucketizer::from_data(&empty, 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(QuantileBucketizer::from_data(&empty, 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(QuantileBucketizer::<f64>::from_weighted_data(&[], 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(RangeBucketizer::equal_frequency(&empty, 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(RobustBucketizer::from_data(&empty, vec![0.0]).unwrap_err(), DataError::EmptyData);
+        }
+
+        #[test]
+        fn nan_only_data_counts_as_empty() {
+            let nans = [f64::NAN, f64::NAN];
+
+            assert_eq!(LinearBucketizer::from_data(&nans, 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(QuantileBucketizer::from_data(&nans, 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(QuantileBucketizer::from_weighted_data(&[(f64::NAN, 1.0)], 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(RangeBucketizer::equal_frequency(&nans, 4).unwrap_err(), DataError::EmptyData);
+            assert_eq!(RobustBucketizer::from_data(&nans, vec![0.0]).unwrap_err(), DataError::EmptyData);
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    mod wide {
+        use crate::bucketize::BucketizeSingle;
+        use crate::bucketizers::fw::FixedWidthBucketizer;
+
+        #[test]
+        fn u128_past_usize_max_saturates() {
+            let bucketizer = FixedWidthBucketizer::new(1u128, 0);
+            let past_max = usize::MAX as u128 + 5;
+
+            // A narrowing cast would wrap this to bucket 4
+            assert_eq!(bucketizer.bucketize(&past_max), usize::MAX);
+        }
+
+        #[test]
+        fn i128_past_usize_max_saturates() {
+            let bucketizer = FixedWidthBucketizer::new(1i128, 0);
+
+            assert_eq!(bucketizer.bucketize(&i128::MAX), usize::MAX);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use serde::{de::DeserializeOwned, Serialize};
+        use crate::bucketize::BucketizeSingle;
+        use crate::bucketizers::fw::FixedWidthBucketizer;
+        use crate::bucketizers::linear::LinearBucketizer;
+        use crate::bucketizers::log::LogarithmicBucketizer;
+        use crate::bucketizers::quantile::QuantileBucketizer;
+        use crate::bucketizers::range::RangeBucketizer;
+
+        fn assert_round_trip<B>(bucketizer: B, samples: &[f64])
+        where
+            B: BucketizeSingle<f64> + Serialize + DeserializeOwned,
+        {
+            let json = serde_json::to_string(&bucketizer).unwrap();
+            let restored: B = serde_json::from_str(&json).unwrap();
+
+            for sample in samples {
+                assert_eq!(bucketizer.bucketize(sample), restored.bucketize(sample));
+            }
+        }
+
+        const SAMPLES: [f64; 8] = [-3.0, 0.0, 1.0, 4.9, 5.0, 12.5, 19.9, 250.0];
+
+        #[test]
+        fn linear_round_trip() {
+            assert_round_trip(LinearBucketizer::new(0.0, 20.0, 4.0), &SAMPLES);
+        }
+
+        #[test]
+        fn fixed_width_round_trip() {
+            assert_round_trip(FixedWidthBucketizer::new(5.0, 0.0), &SAMPLES);
+        }
+
+        #[test]
+        fn range_round_trip() {
+            let ranges = vec![(0.0, 5.0), (5.0, 10.0), (10.0, 20.0), (20.0, f64::MAX)];
+            assert_round_trip(RangeBucketizer::new(ranges), &SAMPLES);
+        }
+
+        #[test]
+        fn range_sorted_flag_is_recomputed() {
+            let ranges = vec![(0.0, 5.0), (5.0, 10.0), (10.0, 20.0)];
+            assert_round_trip(RangeBucketizer::new_sorted(ranges), &SAMPLES);
+
+            let json = r#"{"ranges":[[5.0,10.0],[0.0,5.0]],"sorted":true}"#;
+            let restored: RangeBucketizer<f64> = serde_json::from_str(json).unwrap();
+            assert_eq!(restored.try_bucketize(&2.0), Some(1));
+        }
+
+        #[test]
+        fn quantile_round_trip() {
+            assert_round_trip(QuantileBucketizer::new(vec![1.0, 5.0, 15.0], 4), &SAMPLES);
+        }
+
+        #[test]
+        fn log_round_trip() {
+            assert_round_trip(LogarithmicBucketizer::new(0.0, 10.0, 4), &SAMPLES);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod config {
+        use crate::bucketizers::linear::LinearError;
+        use crate::bucketizers::range::RangeError;
+        use crate::config::{BucketizerSpec, SpecError};
+
+        fn bucketize(json: &str, value: f64) -> usize {
+            let spec: BucketizerSpec = serde_json::from_str(json).unwrap();
+            spec.build().unwrap().bucketize(&value)
+        }
+
+        fn build_error(json: &str) -> SpecError {
+            let spec: BucketizerSpec = serde_json::from_str(json).unwrap();
+            spec.build().err().unwrap()
+        }
+
+        #[test]
+        fn linear_spec() {
+            let json = r#"{ "type": "linear", "start": 0.0, "end": 20.0, "num_buckets": 4 }"#;
+            assert_eq!(bucketize(json, 12.5), 2);
+        }
+
+        #[test]
+        fn fixed_width_spec() {
+            let json = r#"{ "type": "fixed_width", "width": 5.0, "offset": 0.0 }"#;
+            assert_eq!(bucketize(json, 1000.0), 200);
+
+            let json = r#"{ "type": "fixed_width", "width": 5.0, "offset": 0.0, "max_buckets": 4 }"#;
+            assert_eq!(bucketize(json, 1000.0), 3);
+        }
+
+        #[test]
+        fn range_spec() {
+            let json = r#"{ "type": "range", "ranges": [[0.0, 5.0], [5.0, 10.0], [10.0, 20.0]] }"#;
+            assert_eq!(bucketize(json, 12.5), 2);
+        }
+
+        #[test]
+        fn quantile_spec() {
+            let json = r#"{ "type": "quantile", "quantiles": [25.0, 50.0, 75.0], "n_quantiles": 4 }"#;
+            assert_eq!(bucketize(json, 55.0), 2);
+        }
+
+        #[test]
+        fn unknown_type_is_rejected() {
+            let json = r#"{ "type": "spline", "knots": [1.0] }"#;
+            assert!(serde_json::from_str::<BucketizerSpec>(json).is_err());
+        }
+
+        #[test]
+        fn invalid_specs_are_rejected() {
+            let json = r#"{ "type": "linear", "start": 0.0, "end": 20.0, "num_buckets": 0 }"#;
+            assert_eq!(build_error(json), SpecError::Linear(LinearError::ZeroBuckets));
+
+            let json = r#"{ "type": "linear", "start": 20.0, "end": 0.0, "num_buckets": 4 }"#;
+            assert_eq!(build_error(json), SpecError::Linear(LinearError::EmptyRange));
+
+            let json = r#"{ "type": "range", "ranges": [] }"#;
+            assert_eq!(build_error(json), SpecError::Range(RangeError::Empty));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    mod rayon {
+        use crate::bucketize::{Bucketize, LengthMismatch, ParBucketize};
+        use crate::bucketizers::linear::LinearBucketizer;
+
+        #[test]
+        fn par_bucketize_matches_sequential() {
+            let data: Vec<f64> = (0..100_000).map(|i| (i % 250) as f64 / 10.0).collect();
+            let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+
+            let sequential = bucketizer.bucketize_iter(data.iter().copied());
+            let parallel = bucketizer.par_bucketize(&data);
+
+            assert_eq!(sequential, parallel);
+        }
+
+        #[test]
+        fn par_bucketize_into_matches_sequential() {
+            let data: Vec<f64> = (0..100_000).map(|i| (i % 250) as f64 / 10.0).collect();
+            let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+
+            let mut out = vec![usize::MAX; data.len()];
+            bucketizer.par_bucketize_into(&data, &mut out).unwrap();
+
+            assert_eq!(out, bucketizer.bucketize_iter(data.iter().copied()));
+        }
+
+        #[test]
+        fn par_bucketize_into_rejects_length_mismatch() {
+            let bucketizer = LinearBucketizer::new(0.0, 20.0, 4.0);
+            let mut out = vec![usize::MAX; 2];
+
+            let result = bucketizer.par_bucketize_into(&[1.0, 2.0, 3.0], &mut out);
+
+            assert_eq!(result, Err(LengthMismatch { expected: 3, actual: 2 }));
+            assert_eq!(out, vec![usize::MAX; 2]);
+        }
+    }
 }
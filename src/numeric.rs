@@ -0,0 +1,81 @@
+use core::ops::{Div, Sub};
+use crate::into_usize::IntoUsize;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The bounds shared by the arithmetic bucketizers, which compute a bucket
+/// index by subtracting an origin, dividing by a width, and converting the
+/// quotient with `IntoUsize`.
+///
+/// `Numeric` is implemented for every type meeting those bounds, including
+/// all primitive integers and floats, and is sealed, so it cannot be
+/// implemented manually.
+///
+/// # Example
+///
+/// ```
+/// use buckets::numeric::Numeric;
+///
+/// fn bucket_of<T: Numeric>(value: T, origin: T, width: T) -> usize {
+///     if value < origin {
+///         return 0;
+///     }
+///     ((value - origin) / width).into_usize()
+/// }
+///
+/// assert_eq!(bucket_of(12, 0, 5), 2);
+/// assert_eq!(bucket_of(12.5, 10.0, 0.5), 5);
+/// assert_eq!(bucket_of(-3.0, 0.0, 5.0), 0);
+/// ```
+pub trait Numeric:
+    private::Sealed
+    + PartialOrd
+    + Sub<Output = Self>
+    + Div<Output = Self>
+    + IntoUsize
+    + Copy
+{}
+
+impl<T> private::Sealed for T
+where
+    T: PartialOrd + Sub<Output = T> + Div<Output = T> + IntoUsize + Copy,
+{}
+
+impl<T> Numeric for T
+where
+    T: PartialOrd + Sub<Output = T> + Div<Output = T> + IntoUsize + Copy,
+{}
+
+/// Returns `value - base`, or `T::default()`, i.e. zero for the primitive
+/// types, if `value < base`.
+///
+/// Subtracting an origin from a value below it would underflow unsigned
+/// types, so the arithmetic bucketizers clamp the offset at zero instead.
+///
+/// # Example
+///
+/// ```
+/// use buckets::numeric::checked_offset;
+///
+/// assert_eq!(checked_offset(12u8, 10), 2);
+/// assert_eq!(checked_offset(3u8, 10), 0);
+/// assert_eq!(checked_offset(3u64, u64::MAX), 0);
+///
+/// assert_eq!(checked_offset(-3i32, 10), 0);
+/// assert_eq!(checked_offset(3i32, -10), 13);
+///
+/// assert_eq!(checked_offset(12.5f64, 10.0), 2.5);
+/// assert_eq!(checked_offset(-0.5f64, 0.0), 0.0);
+/// ```
+pub fn checked_offset<T>(value: T, base: T) -> T
+where
+    T: PartialOrd + Sub<Output = T> + Default,
+{
+    if value < base {
+        T::default()
+    } else {
+        value - base
+    }
+}
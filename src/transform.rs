@@ -0,0 +1,255 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use num_traits::ToPrimitive;
+use crate::bucketize::{BucketBounds, BucketCount, BucketizeSingle};
+
+/// Coarsens bucket indices by merging every `factor` adjacent buckets 
+/// into one, mapping each index `i` to `i / factor`.
+///
+/// This downsamples a bucketization without re-running the bucketizer.
+///
+/// # Panics
+///
+/// Panics if `factor` is 0.
+///
+/// # Example
+///
+/// ```
+/// use buckets::transform::merge_buckets;
+///
+/// assert_eq!(merge_buckets(&[0, 1, 2, 3], 2), vec![0, 0, 1, 1]);
+/// assert_eq!(merge_buckets(&[0, 4, 5, 9], 3), vec![0, 1, 1, 3]);
+/// ```
+///
+/// ```should_panic
+/// use buckets::transform::merge_buckets;
+///
+/// merge_buckets(&[0, 1, 2, 3], 0);
+/// ```
+pub fn merge_buckets(indices: &[usize], factor: usize) -> Vec<usize> {
+    assert!(factor > 0, "merge factor must be greater than 0");
+
+    indices.iter().map(|index| index / factor).collect()
+}
+
+/// Bucketizes an aggregate of every sliding window of `window` 
+/// consecutive values in `data`, e.g. a moving average of a time series.
+///
+/// Element `i` of the result is the bucket of `agg(&data[i..i + window])`, 
+/// so there is one index per window, and none if `data` is shorter than 
+/// `window`.
+///
+/// # Panics
+///
+/// Panics if `window` is 0.
+///
+/// # Example
+///
+/// ```
+/// use buckets::bucketizers::fw::FixedWidthBucketizer;
+/// use buckets::transform::windowed_bucketize;
+///
+/// let bucketizer = FixedWidthBucketizer::new(2, 0);
+/// let mean = |window: &[i32]| window.iter().sum::<i32>() / window.len() as i32;
+///
+/// // The window means are 2, 4 and 6
+/// assert_eq!(windowed_bucketize(&bucketizer, &[1, 3, 5, 7], 2, mean), vec![1, 2, 3]);
+/// assert!(windowed_bucketize(&bucketizer, &[1], 2, mean).is_empty());
+/// ```
+pub fn windowed_bucketize<T, B>(
+    bucketizer: &B,
+    data: &[T],
+    window: usize,
+    agg: impl Fn(&[T]) -> T,
+) -> Vec<usize>
+where
+    T: PartialOrd + Copy,
+    B: BucketizeSingle<T> + ?Sized,
+{
+    assert!(window > 0, "window size must be greater than 0");
+
+    data.windows(window)
+        .map(|values| bucketizer.bucketize(&agg(values)))
+        .collect()
+}
+
+/// Approximately re-expresses `old_counts`, binned by `old`, as counts for
+/// the buckets of `new`.
+///
+/// Each old bucket's count is assumed to be spread evenly across its
+/// interval, i.e. to have a constant density, and is split between the
+/// new buckets in proportion to how much of the interval each of them
+/// overlaps. A zero-width old bucket goes entirely to the new bucket
+/// containing it. The fractional shares are rounded with the largest
+/// remainder method, so the total is preserved whenever the new buckets
+/// cover the old ones, and counts outside every new bucket are dropped.
+///
+/// The result has one element per new bucket if `new` reports a bucket
+/// count, and otherwise extends up to the last new bucket that starts
+/// before the end of the old buckets, stopping early at the first new
+/// bucket with no width so that a degenerate bucketizer, such as a
+/// zero-width `FixedWidthBucketizer`, cannot loop forever. Buckets are
+/// assumed to be ascending.
+///
+/// # Example
+///
+/// ```
+/// use buckets::bucketizers::linear::LinearBucketizer;
+/// use buckets::transform::rebin;
+///
+/// let wide = LinearBucketizer::new(0.0, 20.0, 4.0);
+/// let narrow = LinearBucketizer::new(0.0, 20.0, 8.0);
+///
+/// let counts = rebin(&wide, &[10, 4, 7, 0], &narrow);
+///
+/// assert_eq!(counts, vec![5, 5, 2, 2, 4, 3, 0, 0]);
+/// assert_eq!(counts.iter().sum::<usize>(), 21);
+/// ```
+pub fn rebin<T, A, B>(old: &A, old_counts: &[usize], new: &B) -> Vec<usize>
+where
+    T: PartialOrd + Copy + ToPrimitive,
+    A: BucketBounds<T>,
+    B: BucketBounds<T> + BucketCount,
+{
+    let old_bounds: Vec<(f64, f64)> = (0..old_counts.len())
+        .map(|index| old.bucket_bounds(index).map_or((f64::NAN, f64::NAN), to_f64_bounds))
+        .collect();
+    let old_end = old_bounds
+        .iter()
+        .map(|&(_, high)| high)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let new_bounds: Vec<(f64, f64)> = match new.num_buckets() {
+        Some(num_buckets) => (0..num_buckets)
+            .map_while(|index| new.bucket_bounds(index).map(to_f64_bounds))
+            .collect(),
+        None => (0..)
+            .map_while(|index| new.bucket_bounds(index).map(to_f64_bounds))
+            .take_while(|&(low, high)| low < high && low < old_end)
+            .collect(),
+    };
+
+    let mut shares = vec![0.0; new_bounds.len()];
+    for (&count, &(old_low, old_high)) in old_counts.iter().zip(old_bounds.iter()) {
+        let width = old_high - old_low;
+        for (share, &(new_low, new_high)) in shares.iter_mut().zip(new_bounds.iter()) {
+            if width > 0.0 {
+                let overlap = (old_high.min(new_high) - old_low.max(new_low)).max(0.0);
+                *share += count as f64 * overlap / width;
+            } else if new_low <= old_low && old_low < new_high {
+                *share += count as f64;
+            }
+        }
+    }
+
+    round_preserving_total(&shares)
+}
+
+fn to_f64_bounds<T: ToPrimitive>((low, high): (T, T)) -> (f64, f64) {
+    (
+        low.to_f64().unwrap_or(f64::NAN),
+        high.to_f64().unwrap_or(f64::NAN),
+    )
+}
+
+/// Rounds `shares` down, then hands the units lost to rounding to the
+/// shares with the largest remainders, preferring lower indices on ties.
+fn round_preserving_total(shares: &[f64]) -> Vec<usize> {
+    let mut counts: Vec<usize> = shares.iter().map(|&share| share as usize).collect();
+    let total = (shares.iter().sum::<f64>() + 0.5) as usize;
+    let assigned: usize = counts.iter().sum();
+
+    let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+    by_remainder.sort_by(|&a, &b| {
+        let remainder = |index: usize| shares[index] - counts[index] as f64;
+        remainder(b).partial_cmp(&remainder(a)).unwrap_or(Ordering::Equal)
+    });
+    for index in by_remainder.into_iter().take(total.saturating_sub(assigned)) {
+        counts[index] += 1;
+    }
+    counts
+}
+
+/// The error returned by `pack_nibbles` when a bucket index does not fit 
+/// in four bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NibbleOverflow {
+    /// The position of the offending index in the input.
+    pub position: usize,
+    /// The offending index, which is greater than 15.
+    pub index: usize,
+}
+
+impl fmt::Display for NibbleOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bucket index {} at position {} does not fit in a nibble", self.index, self.position)
+    }
+}
+
+impl Error for NibbleOverflow {}
+
+/// Packs bucket indices below 16 two to a byte, halving the storage of 
+/// bucketizations with at most 16 buckets.
+///
+/// Index `2 * i` is stored in the low nibble of byte `i` and index 
+/// `2 * i + 1` in its high nibble. An odd number of indices leaves the 
+/// high nibble of the last byte zero, so `unpack_nibbles` needs the 
+/// original length.
+///
+/// Returns `NibbleOverflow` for the first index greater than 15.
+///
+/// # Example
+///
+/// ```
+/// use buckets::transform::{pack_nibbles, unpack_nibbles, NibbleOverflow};
+///
+/// let indices = vec![1, 15, 0, 7, 3];
+/// let packed = pack_nibbles(&indices).unwrap();
+///
+/// assert_eq!(packed, vec![0xf1, 0x70, 0x03]);
+/// assert_eq!(unpack_nibbles(&packed, indices.len()), indices);
+///
+/// assert_eq!(
+///     pack_nibbles(&[3, 16, 2]),
+///     Err(NibbleOverflow { position: 1, index: 16 }),
+/// );
+/// ```
+pub fn pack_nibbles(indices: &[usize]) -> Result<Vec<u8>, NibbleOverflow> {
+    let mut packed = vec![0; indices.len().div_ceil(2)];
+    for (position, &index) in indices.iter().enumerate() {
+        if index > 0xf {
+            return Err(NibbleOverflow { position, index });
+        }
+        packed[position / 2] |= (index as u8) << (4 * (position % 2));
+    }
+    Ok(packed)
+}
+
+/// Unpacks the first `len` bucket indices from nibbles packed by 
+/// `pack_nibbles`.
+///
+/// # Panics
+///
+/// Panics if `packed` holds fewer than `len` nibbles.
+///
+/// # Example
+///
+/// ```
+/// use buckets::transform::{pack_nibbles, unpack_nibbles};
+///
+/// let indices: Vec<usize> = (0..16).rev().collect();
+/// let packed = pack_nibbles(&indices).unwrap();
+///
+/// assert_eq!(packed.len(), 8);
+/// assert_eq!(unpack_nibbles(&packed, 16), indices);
+/// assert_eq!(unpack_nibbles(&packed, 3), vec![15, 14, 13]);
+/// ```
+pub fn unpack_nibbles(packed: &[u8], len: usize) -> Vec<usize> {
+    assert!(len <= packed.len() * 2, "packed data holds fewer than len nibbles");
+
+    (0..len)
+        .map(|position| ((packed[position / 2] >> (4 * (position % 2))) & 0xf) as usize)
+        .collect()
+}
@@ -0,0 +1,31 @@
+#![no_std]
+
+use buckets::bucketize::BucketizeSingle;
+use buckets::bucketizers::fw::FixedWidthBucketizer;
+use buckets::bucketizers::linear::LinearBucketizer;
+
+/// Tallies `readings` into four linear buckets without allocating.
+pub fn linear_counts(readings: &[i32]) -> [usize; 4] {
+    let bucketizer = LinearBucketizer::new(0, 100, 4);
+    let mut counts = [0; 4];
+    for reading in readings {
+        counts[bucketizer.bucketize(reading)] += 1;
+    }
+    counts
+}
+
+/// Bucketizes `reading` into buckets 10 units wide.
+pub fn fixed_width_bucket(reading: u32) -> usize {
+    FixedWidthBucketizer::new(10, 0).bucketize(&reading)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucketizes_without_std() {
+        assert_eq!(linear_counts(&[-5, 10, 30, 55, 99, 250]), [2, 1, 1, 2]);
+        assert_eq!(fixed_width_bucket(42), 4);
+    }
+}